@@ -1,8 +1,19 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    panic::{catch_unwind, set_hook, take_hook, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
 
+use crate::helper::input::normalize;
 use proc_macro_lib::include_all_day_files;
 
+mod repl;
+
 include_all_day_files!("change to pull files");
+
+//named solvers for one part, the first one is what runs by default
+type Variants<T> = &'static [(&'static str, fn(&str) -> T)];
+
 trait Time<T>: Fn() -> T {
     fn time(&self, part: usize);
 }
@@ -13,10 +24,105 @@ where
     U: Display,
 {
     fn time(&self, part: usize) {
-        use std::time::Instant;
         let now = Instant::now();
         print!("PART {part}: ");
         println!("{}", self());
         println!(" took {:.2?}", now.elapsed());
     }
 }
+
+//every parser sees its input through here
+fn load(input: &str, report: bool) -> String {
+    let (text, changes) = normalize(input);
+    if report {
+        println!("input: {changes}");
+    }
+    text
+}
+
+fn run_part<T: Display>(part: usize, input: &str, variants: Variants<T>, name: Option<&str>) {
+    let (_, solver) = name
+        .and_then(|name| {
+            let found = variants.iter().find(|(n, _)| *n == name);
+            if found.is_none() {
+                println!("no variant {name} for part {part}, using default");
+            }
+            found
+        })
+        .unwrap_or(&variants[0]);
+    (|| solver(input)).time(part);
+}
+
+//times every variant and checks they all give the same answer
+fn bench_part<T>(part: usize, input: &str, variants: Variants<T>, runs: u32) -> bool
+where
+    T: PartialEq + Display,
+{
+    let mut answers = Vec::new();
+    for (name, solver) in variants {
+        let mut total = Duration::ZERO;
+        let mut answer = None;
+        for _ in 0..runs {
+            let now = Instant::now();
+            answer = Some(solver(input));
+            total += now.elapsed();
+        }
+        let answer = answer.expect("runs must be at least 1");
+        println!("PART {part} {name}: {answer}");
+        println!(" took {:.2?} on average", total / runs);
+        answers.push(answer);
+    }
+
+    let agrees = answers.windows(2).all(|w| w[0] == w[1]);
+    if !agrees {
+        println!("PART {part} variants disagree");
+    }
+    agrees
+}
+
+//prints the input and both answers if the solver and the reference disagree
+fn diff_part<T>(
+    part: usize,
+    name: &str,
+    input: &str,
+    solver: fn(&str) -> T,
+    reference: fn(&str) -> T,
+) -> bool
+where
+    T: PartialEq + Display,
+{
+    let answer = catch_answer(|| solver(input));
+    let expected = catch_answer(|| reference(input));
+    match (&answer, &expected) {
+        (Ok(a), Ok(b)) if a == b => return true,
+        //both rejecting an input counts as agreeing, e.g. a part 2 example run through part 1
+        (Err(_), Err(_)) => return true,
+        _ => {}
+    }
+
+    println!("PART {part} differs on {name}");
+    for (label, result) in [("solver", answer), ("reference", expected)] {
+        match result {
+            Ok(v) => println!(" {label}: {v}"),
+            Err(message) => println!(" {label} panicked: {message}"),
+        }
+    }
+    println!();
+    print!("{input}");
+    false
+}
+
+//a panic counts as an answer, so one bad input is reported instead of ending the run
+fn catch_answer<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = take_hook();
+    set_hook(Box::new(|_| {}));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    set_hook(hook);
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_owned())
+    })
+}
@@ -1,9 +1,14 @@
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::rng::Rng;
 use regex::Regex;
 
-const DAY01_INPUT: &str = include_str!(r"input\day01.txt");
+pub const INPUT: &str = include_str!(r"input\day01.txt");
+pub const EXAMPLES: &[&str] = &[];
+pub const PART1: Variants<i32> = &[("default", part1)];
+pub const PART2: Variants<i32> = &[("default", part2)];
 
-pub fn part1() -> i32 {
-    let input = DAY01_INPUT;
+pub fn part1(input: &str) -> i32 {
     let re = Regex::new(r"^[^\d]*(\d).*(\d)[^\d]*$|^[^\d]*(\d)[^\d]*$").unwrap();
     input
         .lines()
@@ -27,8 +32,7 @@ pub fn part1() -> i32 {
         .sum()
 }
 
-pub fn part2() -> i32 {
-    let input = DAY01_INPUT;
+pub fn part2(input: &str) -> i32 {
     let n_strings = r"one|two|three|four|five|six|seven|eight|nine";
     let rev_n_strings = rev(n_strings);
     let reg = Regex::new(&format!("\\d|{n_strings}")).unwrap();
@@ -68,3 +72,102 @@ fn match_to_digit(s: &str) -> &str {
         s => s,
     }
 }
+
+pub fn repl(input: &str) {
+    let lines: Vec<&str> = input.lines().collect();
+    repl::run(vec![
+        Command::new(
+            "line",
+            "<n>",
+            "show line n and its calibration values",
+            |args| {
+                let n: usize = arg(args, 0, "line number")?;
+                let line = lines
+                    .get(n.wrapping_sub(1))
+                    .ok_or_else(|| format!("no line {n}"))?;
+                Ok(format!(
+                    "{line}\npart 1: {}\npart 2: {}",
+                    part1(line),
+                    part2(line)
+                ))
+            },
+        ),
+        Command::new(
+            "value",
+            "<text>",
+            "calibration values of any text",
+            |args| {
+                let text = args.join(" ");
+                Ok(format!(
+                    "part 1: {}\npart 2: {}",
+                    part1(&text),
+                    part2(&text)
+                ))
+            },
+        ),
+    ]);
+}
+
+pub fn reference1(input: &str) -> i32 {
+    input
+        .lines()
+        .map(|line| {
+            let digits: Vec<i32> = line
+                .chars()
+                .filter_map(|c| c.to_digit(10))
+                .map(|d| d as i32)
+                .collect();
+            digits.first().unwrap() * 10 + digits.last().unwrap()
+        })
+        .sum()
+}
+
+pub fn reference2(input: &str) -> i32 {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    input
+        .lines()
+        .map(|line| {
+            //try every starting position, so overlapping words like "twone" both count
+            let digits: Vec<i32> = (0..line.len())
+                .filter_map(|i| {
+                    let rest = &line[i..];
+                    rest.chars()
+                        .next()
+                        .and_then(|c| c.to_digit(10))
+                        .map(|d| d as i32)
+                        .or_else(|| {
+                            (1..)
+                                .zip(WORDS)
+                                .find_map(|(d, w)| rest.starts_with(w).then_some(d))
+                        })
+                })
+                .collect();
+            digits.first().unwrap() * 10 + digits.last().unwrap()
+        })
+        .sum()
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut out = String::new();
+    for _ in 0..10 * scale {
+        let mut line = String::new();
+        for _ in 0..rng.range(1..=6) {
+            match rng.range(0..4) {
+                0 => line.push(char::from_digit(rng.range(1..=9), 10).unwrap()),
+                1 => line.push_str(rng.choose::<&str>(&WORDS)),
+                _ => line.push(rng.range(b'a'..=b'z') as char),
+            }
+        }
+        //every line needs at least one real digit for part 1
+        let at = rng.range(0..=line.len());
+        line.insert(at, char::from_digit(rng.range(1..=9), 10).unwrap());
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
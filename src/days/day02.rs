@@ -1,8 +1,14 @@
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
 use crate::helper::grouper::Grouper;
+use crate::helper::rng::Rng;
 use regex::Regex;
 use std::hash::Hash;
 
-const DAY02_INPUT: &str = include_str!(r"input\day02.txt");
+pub const INPUT: &str = include_str!(r"input\day02.txt");
+pub const EXAMPLES: &[&str] = &[];
+pub const PART1: Variants<i64> = &[("default", part1)];
+pub const PART2: Variants<i64> = &[("default", part2)];
 
 #[derive(PartialEq, Eq, Hash)]
 enum Color {
@@ -65,8 +71,8 @@ fn get_games(input: &str) -> Vec<Game> {
         .collect()
 }
 
-pub fn part1() -> i64 {
-    get_games(DAY02_INPUT)
+pub fn part1(input: &str) -> i64 {
+    get_games(input)
         .iter()
         .map(|g| {
             for pick in g.turns.iter().map(|t| &t.picks).flatten() {
@@ -82,8 +88,8 @@ pub fn part1() -> i64 {
         .sum()
 }
 
-pub fn part2() -> i64 {
-    get_games(DAY02_INPUT)
+pub fn part2(input: &str) -> i64 {
+    get_games(input)
         .iter()
         .map(|g| {
             g.turns
@@ -97,3 +103,103 @@ pub fn part2() -> i64 {
         })
         .sum()
 }
+
+pub fn repl(input: &str) {
+    let games = get_games(input);
+    repl::run(vec![Command::new(
+        "game",
+        "<id>",
+        "show the most cubes of each color a game needed",
+        |args| {
+            let id: i64 = arg(args, 0, "game id")?;
+            let game = games
+                .iter()
+                .find(|g| g.id == id)
+                .ok_or_else(|| format!("no game {id}"))?;
+            let most = |color: Color| {
+                game.turns
+                    .iter()
+                    .flat_map(|t| &t.picks)
+                    .filter(|p| p.color == color)
+                    .map(|p| p.count)
+                    .max()
+                    .unwrap_or(0)
+            };
+            let (red, green, blue) = (most(Color::Red), most(Color::Green), most(Color::Blue));
+            Ok(format!(
+                "{} turns, most red {red}, green {green}, blue {blue}\npossible: {}",
+                game.turns.len(),
+                red <= 12 && green <= 13 && blue <= 14
+            ))
+        },
+    )]);
+}
+
+//max cubes of each color seen in a game as [red, green, blue]
+fn reference_maxima(line: &str) -> (i64, [i64; 3]) {
+    let (game, turns) = line.split_once(": ").unwrap();
+    let id = game.strip_prefix("Game ").unwrap().parse().unwrap();
+    let mut maxima = [0; 3];
+    for pick in turns.split("; ").flat_map(|t| t.split(", ")) {
+        let (count, color) = pick.split_once(' ').unwrap();
+        let i = ["red", "green", "blue"]
+            .iter()
+            .position(|&c| c == color)
+            .unwrap();
+        maxima[i] = maxima[i].max(count.parse().unwrap());
+    }
+    (id, maxima)
+}
+
+pub fn reference1(input: &str) -> i64 {
+    input
+        .lines()
+        .map(reference_maxima)
+        .filter(|(_, [r, g, b])| *r <= 12 && *g <= 13 && *b <= 14)
+        .map(|(id, _)| id)
+        .sum()
+}
+
+//the power of a game is the product of the most cubes shown of each color. this relies on
+//every game showing all three colors, as the puzzle's do and generate makes sure of: a
+//missing color would count as 0 here, while part 2 leaves it out of the product
+pub fn reference2(input: &str) -> i64 {
+    input
+        .lines()
+        .map(reference_maxima)
+        .map(|(_, [r, g, b])| r * g * b)
+        .sum()
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=10 * scale {
+        let mut turns: Vec<Vec<&str>> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = vec!["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                colors.truncate(rng.range(1..=3));
+                colors
+            })
+            .collect();
+        //real games show every color at least once, so slip any missing one into a turn
+        for color in ["red", "green", "blue"] {
+            if !turns.iter().flatten().any(|&c| c == color) {
+                let i = rng.range(0..turns.len());
+                turns[i].push(color);
+            }
+        }
+        let turns: Vec<String> = turns
+            .iter()
+            .map(|colors| {
+                colors
+                    .iter()
+                    .map(|color| format!("{} {}", rng.range(1..=20), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        out.push_str(&format!("Game {id}: {}\n", turns.join("; ")));
+    }
+    out
+}
@@ -1,11 +1,18 @@
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::grid::{Edges, Grid};
+use crate::helper::render::{Color, Renderer};
+use crate::helper::rng::Rng;
+use crate::helper::vec2::Vec2;
 use std::collections::HashMap;
 
-const DAY03_INPUT: &str = include_str!(r"input\day03.txt");
+pub const INPUT: &str = include_str!(r"input\day03.txt");
+pub const EXAMPLES: &[&str] = &[];
+pub const PART1: Variants<i32> = &[("default", part1)];
+pub const PART2: Variants<i32> = &[("default", part2)];
 
 struct EngineSchematic {
-    data: Vec<char>,
-    width: usize,
-    _height: usize,
+    grid: Grid<char>,
 }
 
 struct Part {
@@ -16,62 +23,43 @@ struct Part {
 }
 
 struct Gear<'a> {
-    _pos: (i32, i32),
+    pos: Vec2<i32>,
     ratio: i32,
     adjacent_parts: Vec<&'a Part>,
 }
 
 impl EngineSchematic {
     fn new(input: &str) -> EngineSchematic {
-        let mut width: usize = 0;
-        let mut height: usize = 0;
-
-        let data: Vec<char> = input
-            .lines()
-            .map(|l| {
-                height += 1;
-                let b: Vec<char> = l.trim().as_bytes().iter().map(|b| *b as char).collect();
-                if width == 0 {
-                    width = b.len()
-                }
-                b
-            })
-            .flatten()
-            .collect();
-
+        let grid = Grid::parse(input, |_, c| {
+            if c.is_ascii() {
+                Ok(c)
+            } else {
+                Err(format!("unexpected character: {c}"))
+            }
+        });
         EngineSchematic {
-            width,
-            _height: height,
-            data,
+            grid: grid.unwrap_or_else(|e| panic!("could not parse schematic: {e}")),
         }
     }
 
     fn get(&self, x: i32, y: i32) -> &char {
-        self._get_with_index(self.to_index(x, y))
+        self.grid.get(Vec2::new(x, y)).unwrap_or(&'.')
     }
 
-    fn get_with_pos(&self, pos: &(i32, i32)) -> &char {
-        self.get(pos.0, pos.1)
-    }
-
-    fn _get_with_index(&self, i: i32) -> &char {
-        if i < 0 {
-            return &'.';
-        }
-        self.data.get(i as usize).unwrap_or(&'.')
+    fn get_with_pos(&self, pos: &Vec2<i32>) -> &char {
+        self.get(pos.x, pos.y)
     }
 
     fn get_parts(&self) -> Vec<Part> {
         let mut result: Vec<Part> = Vec::new();
         let mut start_x = -1;
 
-        for i in 0..self.data.len() as i32 {
-            let (x, y) = self.from_index(i);
+        for Vec2 { x, y } in self.grid.positions() {
             let c = self.get(x, y);
             let n = self.get(x + 1, y);
 
             if is_digit(c) && start_x == -1 {
-                start_x = self.from_index(i).0
+                start_x = x
             }
 
             if !is_digit(n) && start_x != -1 {
@@ -91,7 +79,7 @@ impl EngineSchematic {
     }
 
     fn get_gears<'a>(&self, parts: &'a Vec<Part>) -> Vec<Gear<'a>> {
-        let mut result: HashMap<(i32, i32), Gear> = HashMap::new();
+        let mut result: HashMap<Vec2<i32>, Gear> = HashMap::new();
         for part in parts {
             for pos in self
                 .adjacent_positions(part.start_x, part.end_x, part.y)
@@ -103,7 +91,7 @@ impl EngineSchematic {
                     result.insert(
                         pos,
                         Gear {
-                            _pos: pos,
+                            pos,
                             ratio: -1,
                             adjacent_parts: vec![&part],
                         },
@@ -124,7 +112,7 @@ impl EngineSchematic {
 
     fn check_is_part(&self, start_x: i32, end_x: i32, y: i32) -> bool {
         self.adjacent_positions(start_x, end_x, y)
-            .any(|pos| is_symbol(self.get(pos.0, pos.1)))
+            .any(|pos| is_symbol(self.get_with_pos(&pos)))
     }
 
     fn adjacent_positions(
@@ -132,38 +120,18 @@ impl EngineSchematic {
         start_x: i32,
         end_x: i32,
         y: i32,
-    ) -> impl Iterator<Item = (i32, i32)> {
-        let mut result = Vec::new();
-        for x in start_x - 1..=end_x + 1 {
-            result.push((x, y - 1));
-            result.push((x, y + 1));
-        }
-        result.push((start_x - 1, y));
-        result.push((end_x + 1, y));
-        result.into_iter()
+    ) -> impl Iterator<Item = Vec2<i32>> + '_ {
+        self.grid
+            .around(Vec2::new(start_x, y), Vec2::new(end_x, y), Edges::Bounded)
     }
 
     fn get_id(&self, start_x: i32, end_x: i32, y: i32) -> i32 {
-        let i0 = self.to_index(start_x, y) as usize;
-        let i1 = self.to_index(end_x, y) as usize;
-        self.data[i0..=i1]
+        self.grid.row(y as usize)[start_x as usize..=end_x as usize]
             .iter()
             .collect::<String>()
             .parse()
             .unwrap()
     }
-
-    fn to_index(&self, x: i32, y: i32) -> i32 {
-        if x < 0 || y < 0 || x >= self.width as i32 || y >= self._height as i32 {
-            -1
-        } else {
-            x + y * self.width as i32
-        }
-    }
-
-    fn from_index(&self, i: i32) -> (i32, i32) {
-        (i % self.width as i32, i / self.width as i32)
-    }
 }
 
 fn is_digit(c: &char) -> bool {
@@ -178,16 +146,178 @@ fn is_gear(c: &char) -> bool {
     *c == '*'
 }
 
-pub fn part1() -> i32 {
-    EngineSchematic::new(DAY03_INPUT)
+pub fn part1(input: &str) -> i32 {
+    EngineSchematic::new(input)
         .get_parts()
         .into_iter()
         .map(|p| p.id)
         .sum()
 }
 
-pub fn part2() -> i32 {
-    let es = EngineSchematic::new(DAY03_INPUT);
+pub fn part2(input: &str) -> i32 {
+    let es = EngineSchematic::new(input);
     let parts = es.get_parts();
     es.get_gears(&parts).into_iter().map(|g| g.ratio).sum()
 }
+
+pub fn repl(input: &str) {
+    let es = EngineSchematic::new(input);
+    let parts = es.get_parts();
+    let gears = es.get_gears(&parts);
+    repl::run(vec![
+        Command::new(
+            "at",
+            "<x> <y>",
+            "show a tile and the part numbers on or next to it",
+            |args| {
+                let x: i32 = arg(args, 0, "x")?;
+                let y: i32 = arg(args, 1, "y")?;
+                let touching: Vec<String> = parts
+                    .iter()
+                    .filter(|p| p.y.abs_diff(y) <= 1 && x >= p.start_x - 1 && x <= p.end_x + 1)
+                    .map(|p| p.id.to_string())
+                    .collect();
+                let gear = gears.iter().find(|g| g.pos == Vec2::new(x, y));
+                Ok(format!(
+                    "{}\nparts: {}\ngear ratio: {}",
+                    es.get(x, y),
+                    touching.join(", "),
+                    gear.map_or("none".to_owned(), |g| g.ratio.to_string())
+                ))
+            },
+        ),
+        Command::new(
+            "show",
+            "<y> <rows>",
+            "draw some rows with parts in green and gears in red",
+            |args| {
+                let y: i32 = arg(args, 0, "y")?;
+                let rows: i32 = arg(args, 1, "rows")?;
+                Ok(Renderer::new(&es.grid, |&c| c)
+                    .overlay(
+                        |pos| {
+                            parts
+                                .iter()
+                                .any(|p| p.y == pos.y && pos.x >= p.start_x && pos.x <= p.end_x)
+                        },
+                        None,
+                        Some(Color::Green),
+                    )
+                    .overlay(
+                        |pos| gears.iter().any(|g| g.pos == pos),
+                        None,
+                        Some(Color::Red),
+                    )
+                    .window(
+                        Vec2::new(0, y),
+                        Vec2::new(es.grid.width() as i32 - 1, y + rows - 1),
+                    )
+                    .render())
+            },
+        ),
+        Command::new("row", "<y>", "list the part numbers on row y", |args| {
+            let y: i32 = arg(args, 0, "y")?;
+            let ids: Vec<String> = parts
+                .iter()
+                .filter(|p| p.y == y)
+                .map(|p| format!("{} at {}..={}", p.id, p.start_x, p.end_x))
+                .collect();
+            Ok(ids.join("\n"))
+        }),
+    ]);
+}
+
+//every number as (value, row, first column, last column)
+fn reference_numbers(grid: &[Vec<char>]) -> Vec<(i32, usize, usize, usize)> {
+    let mut out = Vec::new();
+    for (y, row) in grid.iter().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if row[x].is_ascii_digit() {
+                let start = x;
+                while x < row.len() && row[x].is_ascii_digit() {
+                    x += 1;
+                }
+                let value = row[start..x].iter().collect::<String>().parse().unwrap();
+                out.push((value, y, start, x - 1));
+            } else {
+                x += 1;
+            }
+        }
+    }
+    out
+}
+
+fn reference_touches(number: &(i32, usize, usize, usize), x: usize, y: usize) -> bool {
+    let (_, row, start, end) = *number;
+    y + 1 >= row && y <= row + 1 && x + 1 >= start && x <= end + 1
+}
+
+fn reference_grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|l| l.chars().collect()).collect()
+}
+
+pub fn reference1(input: &str) -> i32 {
+    let grid = reference_grid(input);
+    reference_numbers(&grid)
+        .iter()
+        .filter(|n| {
+            grid.iter().enumerate().any(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .any(|(x, &c)| !c.is_ascii_digit() && c != '.' && reference_touches(n, x, y))
+            })
+        })
+        .map(|n| n.0)
+        .sum()
+}
+
+pub fn reference2(input: &str) -> i32 {
+    let grid = reference_grid(input);
+    let numbers = reference_numbers(&grid);
+    let mut total = 0;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c != '*' {
+                continue;
+            }
+            let adjacent: Vec<_> = numbers
+                .iter()
+                .filter(|n| reference_touches(n, x, y))
+                .collect();
+            if adjacent.len() == 2 {
+                total += adjacent[0].0 * adjacent[1].0;
+            }
+        }
+    }
+    total
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+    let size = 10 * scale;
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        while line.len() < size {
+            let digits = rng.range(1..=3);
+            if rng.chance(1, 4) && line.len() + digits <= size {
+                line.push(char::from_digit(rng.range(1..=9), 10).unwrap());
+                for _ in 1..digits {
+                    line.push(char::from_digit(rng.range(0..=9), 10).unwrap());
+                }
+                //keep numbers apart so they don't merge into one id
+                if line.len() < size {
+                    line.push('.');
+                }
+            } else if rng.chance(1, 6) {
+                line.push(*rng.choose(&SYMBOLS));
+            } else {
+                line.push('.');
+            }
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
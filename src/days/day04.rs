@@ -1,9 +1,15 @@
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::rng::Rng;
 use lazy_static::lazy_static;
 use std::collections::HashSet;
 
 use regex::Regex;
 
-const INPUT_DAY04: &str = include_str!(r"input\day04.txt");
+pub const INPUT: &str = include_str!(r"input\day04.txt");
+pub const EXAMPLES: &[&str] = &[];
+pub const PART1: Variants<i32> = &[("default", part1)];
+pub const PART2: Variants<usize> = &[("default", part2)];
 
 lazy_static! {
     static ref CARD_RE: Regex =
@@ -53,15 +59,15 @@ impl Card {
     }
 }
 
-pub fn part1() -> i32 {
-    Card::all_cards(INPUT_DAY04)
+pub fn part1(input: &str) -> i32 {
+    Card::all_cards(input)
         .into_iter()
         .map(|c| c.get_points())
         .sum()
 }
 
-pub fn part2() -> usize {
-    let cards = Card::all_cards(INPUT_DAY04);
+pub fn part2(input: &str) -> usize {
+    let cards = Card::all_cards(input);
     chain_cards(&cards)
 }
 
@@ -84,3 +90,108 @@ fn hashset_from_numbers(s: &str) -> HashSet<i32> {
         .map(|s| s.as_str().parse::<i32>().unwrap())
         .collect()
 }
+
+pub fn repl(input: &str) {
+    let cards = Card::all_cards(input);
+    repl::run(vec![Command::new(
+        "card",
+        "<n>",
+        "show a card's matches and points",
+        |args| {
+            let n: usize = arg(args, 0, "card number")?;
+            let card = cards
+                .get(n.wrapping_sub(1))
+                .ok_or_else(|| format!("no card {n}"))?;
+            let mut matching: Vec<_> = card
+                .picked_numbers
+                .intersection(&card.winning_numbers)
+                .collect();
+            matching.sort_unstable();
+            Ok(format!(
+                "matching {:?}\nmatches: {}\npoints: {}",
+                matching,
+                card.matches(),
+                card.get_points()
+            ))
+        },
+    )]);
+}
+
+fn reference_matches(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').unwrap();
+            let (winning, picked) = numbers.split_once('|').unwrap();
+            let winning: Vec<&str> = winning.split_whitespace().collect();
+            picked
+                .split_whitespace()
+                .filter(|n| winning.contains(n))
+                .count()
+        })
+        .collect()
+}
+
+pub fn reference1(input: &str) -> i32 {
+    reference_matches(input)
+        .into_iter()
+        .map(|m| if m == 0 { 0 } else { 1 << (m - 1) })
+        .sum()
+}
+
+//each card passes all its copies on to the cards it wins, in order, so by the time a card
+//is reached nothing else can add to it
+pub fn reference2(input: &str) -> usize {
+    let matches = reference_matches(input);
+    let mut copies = vec![1; matches.len()];
+    for card in 0..matches.len() {
+        for won in card + 1..=card + matches[card] {
+            copies[won] += copies[card];
+        }
+    }
+    copies.iter().sum()
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    //copies double along every chain of wins, so a card only wins as many as keeps each card
+    //it wins below this many copies. that holds part 2 under cards * MAX_COPIES at any scale
+    const MAX_COPIES: usize = 1_000_000;
+    let cards = 10 * scale;
+    let mut copies = vec![1; cards];
+    let mut out = String::new();
+    for i in 0..cards {
+        let mut numbers: Vec<i32> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        let (winning, others) = numbers.split_at(10);
+        //a card can't win copies of cards past the end of the table
+        let most = (i + 1..cards)
+            .take(winning.len())
+            .take_while(|&j| copies[j] + copies[i] <= MAX_COPIES)
+            .count();
+        let matches = rng.range(0..=most);
+        for j in i + 1..=i + matches {
+            copies[j] += copies[i];
+        }
+        let mut picked: Vec<i32> = winning[..matches]
+            .iter()
+            .chain(others[..25 - matches].iter())
+            .copied()
+            .collect();
+        rng.shuffle(&mut picked);
+        out.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            i + 1,
+            format_numbers(winning),
+            format_numbers(&picked)
+        ));
+    }
+    out
+}
+
+fn format_numbers(numbers: &[i32]) -> String {
+    numbers
+        .iter()
+        .map(|n| format!("{n:>2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -1,12 +1,16 @@
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::rng::Rng;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::{BTreeMap, HashMap};
 
-const _DAY05_SIMPLE_INPUT: &str = include_str!(r"input\day05_simple.txt");
-const DAY05_INPUT: &str = include_str!(r"input\day05.txt");
+pub const INPUT: &str = include_str!(r"input\day05.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day05_simple.txt")];
+pub const PART1: Variants<String> = &[("default", part1)];
+pub const PART2: Variants<String> = &[("default", part2)];
 
 lazy_static! {
-    static ref SECTION_RE: Regex = Regex::new(r"(\r\n){2}|\r{2}|\n{2}").unwrap();
     static ref NAME_RE: Regex = Regex::new(r"(?P<source>.*)-to-(?P<destination>.*) map:").unwrap();
     static ref NUM_RE: Regex = Regex::new(r"\d+").unwrap();
     static ref ENTRY_RE: Regex = Regex::new(r"(\d+)\s(\d+)\s(\d+)").unwrap();
@@ -45,45 +49,27 @@ impl Entry {
         }
     }
 
-    //returns the converted ranges, and then the leftover range
+    //returns the converted ranges, and then the leftover range. the part before the source
+    //isn't covered by this entry and passes through as it is, the overlap is moved, and
+    //whatever is past the source is left over for the entries after this one
     fn get_ranges(&self, range: &Range) -> (Option<Vec<Range>>, Option<Range>) {
-        if self.source.in_range(range.start) && self.source.in_range(range.end() - 1) {
-            return (
-                Some(vec![Range {
-                    start: self.apply_range(range.start),
-                    len: range.len,
-                }]),
-                None,
-            );
-        } else if self.source.in_range(range.start) {
-            let new_len = self.source.end() - range.start;
-            return (
-                Some(vec![Range {
-                    start: self.apply_range(range.start),
-                    len: new_len,
-                }]),
-                Some(Range {
-                    start: range.start + new_len,
-                    len: range.len - new_len,
-                }),
-            );
-        } else if self.source.in_range(range.end() - 1) {
-            return (
-                Some(vec![
-                    Range {
-                        start: range.start,
-                        len: self.source.end() - range.start,
-                    },
-                    Range {
-                        start: self.apply_range(self.source.start),
-                        len: self.source.len,
-                    },
-                ]),
-                None,
-            );
-        } else {
-            return (Some(vec![range.clone()]), None);
+        let mut out = Vec::new();
+        let before_end = range.end().min(self.source.start);
+        if range.start < before_end {
+            out.push(Range::new(range.start, before_end - range.start));
+        }
+        let overlap_start = range.start.max(self.source.start);
+        let overlap_end = range.end().min(self.source.end());
+        if overlap_start < overlap_end {
+            out.push(Range::new(
+                self.apply_range(overlap_start),
+                overlap_end - overlap_start,
+            ));
         }
+        let after_start = range.start.max(self.source.end());
+        let leftover =
+            (after_start < range.end()).then(|| Range::new(after_start, range.end() - after_start));
+        (Some(out), leftover)
     }
 }
 
@@ -105,7 +91,7 @@ impl<'a> Almanac<'a> {
         let mut lookup: Lookup = Lookup::new();
         let mut source_to_destination_name: HashMap<&'a str, &'a str> = HashMap::new();
 
-        let mut sections = SECTION_RE.split(input);
+        let mut sections = input.split("\n\n");
         let seed_section = sections.next().unwrap();
         let start_values: Vec<usize> = NUM_RE
             .find_iter(seed_section)
@@ -242,8 +228,8 @@ impl<'a> Almanac<'a> {
 
 const DEBUG: bool = false;
 
-pub fn part1() -> String {
-    let alm = Almanac::new(DAY05_INPUT);
+pub fn part1(input: &str) -> String {
+    let alm = Almanac::new(input);
     let p1 = alm
         .start_values
         .iter()
@@ -253,8 +239,8 @@ pub fn part1() -> String {
     format!("{} = {}", p1.1, p1.0)
 }
 
-pub fn part2() -> String {
-    let alm = Almanac::new(DAY05_INPUT);
+pub fn part2(input: &str) -> String {
+    let alm = Almanac::new(input);
     let mut category = alm.start_category;
     let mut ranges = alm.start_ranges.clone();
     while let Some(v) = alm.apply_category_ranges(category, &ranges) {
@@ -264,3 +250,158 @@ pub fn part2() -> String {
     let min = ranges.iter().min_by_key(|r| r.start).unwrap();
     format!("{} = {}", category, min.start)
 }
+
+pub fn repl(input: &str) {
+    let alm = Almanac::new(input);
+    repl::run(vec![
+        Command::new(
+            "convert",
+            "<category> <value>",
+            "follow a value through every map",
+            |args| {
+                let mut category: &str = arg::<String>(args, 0, "category")
+                    .map(|c| alm.lookup.get_key_value(c.as_str()).map(|(k, _)| *k))?
+                    .ok_or("unknown category")?;
+                let mut value: usize = arg(args, 1, "value")?;
+                let mut steps = vec![format!("{category} {value}")];
+                while let Some((next, next_category)) = alm.convert_forwards(value, category) {
+                    value = next;
+                    category = next_category;
+                    steps.push(format!("{category} {value}"));
+                }
+                Ok(steps.join(" -> "))
+            },
+        ),
+        Command::new(
+            "ranges",
+            "<category> <start> <len>",
+            "follow a range of values through every map",
+            |args| {
+                let mut category: &str = arg::<String>(args, 0, "category")
+                    .map(|c| alm.lookup.get_key_value(c.as_str()).map(|(k, _)| *k))?
+                    .ok_or("unknown category")?;
+                let mut ranges = vec![Range::new(arg(args, 1, "start")?, arg(args, 2, "len")?)];
+                let mut steps = Vec::new();
+                while let Some((next, next_category)) = alm.apply_category_ranges(category, &ranges)
+                {
+                    ranges = next;
+                    category = next_category;
+                    let shown: Vec<String> = ranges
+                        .iter()
+                        .map(|r| format!("{}..{}", r.start, r.end()))
+                        .collect();
+                    steps.push(format!("{category}: {}", shown.join(" ")));
+                }
+                Ok(steps.join("\n"))
+            },
+        ),
+    ]);
+}
+
+//(destination, source, length)
+type ReferenceRange = (usize, usize, usize);
+
+//seeds, then every map as a list of ranges
+fn reference_almanac(input: &str) -> (Vec<usize>, Vec<Vec<ReferenceRange>>) {
+    let mut lines = input.lines();
+    let seeds = lines
+        .next()
+        .unwrap()
+        .strip_prefix("seeds:")
+        .unwrap()
+        .split_whitespace()
+        .map(|n| n.parse().unwrap())
+        .collect();
+    let mut maps: Vec<Vec<ReferenceRange>> = Vec::new();
+    for line in lines {
+        if line.ends_with("map:") {
+            maps.push(Vec::new());
+        } else if let [d, s, l] = line
+            .split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect::<Vec<usize>>()[..]
+        {
+            maps.last_mut().unwrap().push((d, s, l));
+        }
+    }
+    (seeds, maps)
+}
+
+fn reference_location(maps: &[Vec<ReferenceRange>], seed: usize) -> usize {
+    maps.iter().fold(seed, |value, map| {
+        map.iter()
+            .find(|&&(_, s, l)| value >= s && value < s + l)
+            .map(|&(d, s, _)| value - s + d)
+            .unwrap_or(value)
+    })
+}
+
+pub fn reference1(input: &str) -> String {
+    let (seeds, maps) = reference_almanac(input);
+    let min = seeds
+        .iter()
+        .map(|&s| reference_location(&maps, s))
+        .min()
+        .unwrap();
+    format!("location = {min}")
+}
+
+//walks every single seed in every range
+pub fn reference2(input: &str) -> String {
+    let (seeds, maps) = reference_almanac(input);
+    let min = seeds
+        .chunks_exact(2)
+        .flat_map(|c| c[0]..c[0] + c[1])
+        .map(|s| reference_location(&maps, s))
+        .min()
+        .unwrap();
+    format!("location = {min}")
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    const CATEGORIES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    let domain = 1000 * scale;
+    let ranges = 3 * scale;
+
+    let seeds: Vec<String> = (0..2 + scale)
+        .map(|_| format!("{} {}", rng.range(0..domain), rng.range(1..=domain / 10)))
+        .collect();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in CATEGORIES.windows(2) {
+        out.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+
+        //cut the domain into disjoint source ranges, then map a subset of them
+        //onto a shuffled, contiguous block so no two destinations overlap either
+        let mut cuts: Vec<usize> = (0..2 * ranges).map(|_| rng.range(1..domain)).collect();
+        cuts.extend([0, domain]);
+        cuts.sort_unstable();
+        cuts.dedup();
+        let mut sources: Vec<Range> = cuts
+            .windows(2)
+            .map(|w| Range::new(w[0], w[1] - w[0]))
+            .collect();
+        rng.shuffle(&mut sources);
+        sources.truncate(ranges);
+
+        let total: usize = sources.iter().map(|r| r.len).sum();
+        let mut destination = rng.range(0..=domain - total);
+        for source in sources {
+            out.push_str(&format!(
+                "{} {} {}\n",
+                destination, source.start, source.len
+            ));
+            destination += source.len;
+        }
+    }
+    out
+}
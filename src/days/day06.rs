@@ -1,14 +1,21 @@
-const _DAY06_SIMPLE_INPUT: &str = include_str!(r"input\day06_simple.txt");
-const DAY06_INPUT: &str = include_str!(r"input\day06.txt");
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::num::quadratic_above_zero;
+use crate::helper::rng::Rng;
+
+pub const INPUT: &str = include_str!(r"input\day06.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day06_simple.txt")];
+pub const PART1: Variants<u128> = &[("default", part1), ("scan", part1_scan)];
+pub const PART2: Variants<u128> = &[("default", part2), ("scan", part2_scan)];
 
 #[derive(Debug)]
 struct Race {
-    time: usize,
-    distance: usize,
+    time: u128,
+    distance: u128,
 }
 
 impl Race {
-    fn shortest_hold_time(&self) -> usize {
+    fn shortest_hold_time(&self) -> u128 {
         for hold in 1..self.time {
             if (self.time - hold) * hold > self.distance {
                 return hold;
@@ -17,7 +24,7 @@ impl Race {
         return self.time;
     }
 
-    fn longest_hold_time(&self) -> usize {
+    fn longest_hold_time(&self) -> u128 {
         for hold in (1..self.time).rev() {
             if (self.time - hold) * hold > self.distance {
                 return hold;
@@ -26,9 +33,26 @@ impl Race {
         return self.time;
     }
 
-    fn ways_to_win(&self) -> usize {
+    fn ways_to_win_scan(&self) -> u128 {
         1 + self.longest_hold_time() - self.shortest_hold_time()
     }
+
+    //hold * (time - hold) > distance is -hold^2 + time * hold - distance > 0, solved exactly
+    //so it stays right for times too large to scan or to fit in an f64. anything up to
+    //u64 is fine; past that the discriminant stops fitting and this gives an Err
+    fn winning_holds(&self) -> Result<Option<(u128, u128)>, String> {
+        let too_large = |v: u128| format!("{v} is too large to solve");
+        let time = i128::try_from(self.time).map_err(|_| too_large(self.time))?;
+        let distance = i128::try_from(self.distance).map_err(|_| too_large(self.distance))?;
+        Ok(quadratic_above_zero(-1, time, -distance)?
+            .map(|(shortest, longest)| (shortest as u128, longest as u128)))
+    }
+
+    fn ways_to_win(&self) -> Result<u128, String> {
+        Ok(self
+            .winning_holds()?
+            .map_or(0, |(shortest, longest)| 1 + longest - shortest))
+    }
 }
 
 fn get_races(input: &str) -> Vec<Race> {
@@ -40,7 +64,7 @@ fn get_races(input: &str) -> Vec<Race> {
         .nth(1)
         .unwrap()
         .split(" ")
-        .filter_map(|s| s.parse::<usize>().ok());
+        .filter_map(|s| s.parse::<u128>().ok());
     let distances = input
         .lines()
         .nth(1)
@@ -49,7 +73,7 @@ fn get_races(input: &str) -> Vec<Race> {
         .nth(1)
         .unwrap()
         .split(" ")
-        .filter_map(|s| s.parse::<usize>().ok());
+        .filter_map(|s| s.parse::<u128>().ok());
 
     times
         .zip(distances)
@@ -69,7 +93,7 @@ fn get_long_race(input: &str) -> Race {
         .nth(1)
         .unwrap()
         .replace(" ", "")
-        .parse::<usize>()
+        .parse::<u128>()
         .unwrap();
 
     let distance = input
@@ -80,18 +104,174 @@ fn get_long_race(input: &str) -> Race {
         .nth(1)
         .unwrap()
         .replace(" ", "")
-        .parse::<usize>()
+        .parse::<u128>()
         .unwrap();
 
     Race { time, distance }
 }
 
-pub fn part1() -> usize {
-    let races = get_races(DAY06_INPUT);
-    races.iter().map(|r| r.ways_to_win()).product()
+pub fn part1(input: &str) -> u128 {
+    let races = get_races(input);
+    races
+        .iter()
+        .map(|r| r.ways_to_win().unwrap_or_else(|e| panic!("bad race: {e}")))
+        .product()
 }
 
-pub fn part2() -> usize {
-    let race = get_long_race(DAY06_INPUT);
+pub fn part2(input: &str) -> u128 {
+    let race = get_long_race(input);
     race.ways_to_win()
+        .unwrap_or_else(|e| panic!("bad race: {e}"))
+}
+
+pub fn part1_scan(input: &str) -> u128 {
+    let races = get_races(input);
+    races.iter().map(|r| r.ways_to_win_scan()).product()
+}
+
+pub fn part2_scan(input: &str) -> u128 {
+    let race = get_long_race(input);
+    race.ways_to_win_scan()
+}
+
+pub fn repl(input: &str) {
+    let races = get_races(input);
+    let describe = |race: &Race| -> Result<String, String> {
+        let holds = match race.winning_holds()? {
+            Some((shortest, longest)) => {
+                format!("shortest hold {shortest}, longest hold {longest}")
+            }
+            None => "no hold wins".to_owned(),
+        };
+        Ok(format!(
+            "time {} distance {}\n{holds}, ways to win {}",
+            race.time,
+            race.distance,
+            race.ways_to_win()?
+        ))
+    };
+    repl::run(vec![
+        Command::new("race", "<n>", "show how to win race n", |args| {
+            let n: usize = arg(args, 0, "race number")?;
+            let race = races
+                .get(n.wrapping_sub(1))
+                .ok_or_else(|| format!("no race {n}"))?;
+            describe(race)
+        }),
+        Command::new("long", "", "show how to win the long race", |_| {
+            describe(&get_long_race(input))
+        }),
+        Command::new(
+            "ways",
+            "<time> <distance>",
+            "show how to win any race",
+            |args| {
+                describe(&Race {
+                    time: arg(args, 0, "time")?,
+                    distance: arg(args, 1, "distance")?,
+                })
+            },
+        ),
+    ]);
+}
+
+//the longest race worth scanning hold by hold
+const SCAN_LIMIT: u128 = 10_000_000;
+
+//every hold does as well as time - hold, and the winning ones are a single run in the
+//middle, so past SCAN_LIMIT this bisects for where the run starts instead of scanning
+fn reference_ways(time: u128, distance: u128) -> u128 {
+    let wins = |hold: u128| (time - hold) * hold > distance;
+    if time <= SCAN_LIMIT {
+        return (0..=time).filter(|&hold| wins(hold)).count() as u128;
+    }
+    let (mut low, mut high) = (0, time / 2);
+    if !wins(high) {
+        return 0;
+    }
+    while low < high {
+        let mid = (low + high) / 2;
+        if wins(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    time + 1 - 2 * low
+}
+
+fn reference_columns(input: &str) -> Vec<Vec<&str>> {
+    input
+        .lines()
+        .map(|l| l.split_whitespace().skip(1).collect())
+        .collect()
+}
+
+pub fn reference1(input: &str) -> u128 {
+    let columns = reference_columns(input);
+    columns[0]
+        .iter()
+        .zip(columns[1].iter())
+        .map(|(t, d)| reference_ways(t.parse().unwrap(), d.parse().unwrap()))
+        .product()
+}
+
+pub fn reference2(input: &str) -> u128 {
+    let columns = reference_columns(input);
+    reference_ways(
+        columns[0].concat().parse().unwrap(),
+        columns[1].concat().parse().unwrap(),
+    )
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    //part 2 concatenates every column, and the exact solver needs the long race's time to
+    //fit in a u64, so scale adds a two digit race at a time up to the 9 that keeps it at
+    //18 digits. the distances come to at most 36 digits, well inside a u128
+    const MAX_RACES: usize = 9;
+    let count = (2 + scale).min(MAX_RACES);
+    loop {
+        let races: Vec<Race> = (0..count)
+            .map(|_| {
+                let time: u64 = rng.range(7..=99);
+                let best = (time / 2) * (time - time / 2);
+                Race {
+                    time: time.into(),
+                    distance: rng.range(1..best).into(),
+                }
+            })
+            .collect();
+
+        let concat = |f: fn(&Race) -> u128| {
+            races
+                .iter()
+                .map(|r| f(r).to_string())
+                .collect::<String>()
+                .parse::<u128>()
+                .unwrap()
+        };
+        let long = Race {
+            time: concat(|r| r.time),
+            distance: concat(|r| r.distance),
+        };
+        //the long race has to be winnable too
+        if long.distance >= (long.time / 2) * (long.time - long.time / 2) {
+            continue;
+        }
+
+        let width = races
+            .iter()
+            .map(|r| r.distance.to_string().len())
+            .max()
+            .unwrap();
+        let times: String = races
+            .iter()
+            .map(|r| format!(" {:>width$}", r.time))
+            .collect();
+        let distances: String = races
+            .iter()
+            .map(|r| format!(" {:>width$}", r.distance))
+            .collect();
+        return format!("Time:    {times}\nDistance:{distances}\n");
+    }
 }
@@ -1,11 +1,19 @@
-use std::{cmp::Ordering, collections::HashMap};
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::rng::Rng;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
-const _DAY07_SIMPLE_INPUT: &str = include_str!(r"input\day07_simple.txt");
-const DAY07_INPUT: &str = include_str!(r"input\day07.txt");
+pub const INPUT: &str = include_str!(r"input\day07.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day07_simple.txt")];
+pub const PART1: Variants<usize> = &[("default", part1)];
+pub const PART2: Variants<usize> = &[("default", part2)];
 
 const JOKER: char = 'J';
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum HandType {
     HighCard = 0,
     OnePair = 1,
@@ -188,14 +196,142 @@ fn sort_and_calc_winnings(mut hands: Vec<Hand>) -> (usize, Vec<Hand>) {
     )
 }
 
-pub fn part1() -> usize {
-    let hands = get_hands(DAY07_INPUT, false);
+pub fn part1(input: &str) -> usize {
+    let hands = get_hands(input, false);
     let (p1, _) = sort_and_calc_winnings(hands);
     return p1;
 }
 
-pub fn part2() -> usize {
-    let hands = get_hands(DAY07_INPUT, true);
+pub fn part2(input: &str) -> usize {
+    let hands = get_hands(input, true);
     let (p2, _) = sort_and_calc_winnings(hands);
     return p2;
 }
+
+pub fn repl(input: &str) {
+    let (_, hands) = sort_and_calc_winnings(get_hands(input, false));
+    let (_, joker_hands) = sort_and_calc_winnings(get_hands(input, true));
+    let hand = |cards: &str, joker_mode| Hand::new(&format!("{cards} 0"), joker_mode);
+    repl::run(vec![
+        Command::new(
+            "hand",
+            "<cards>",
+            "show a hand's type and rank, with and without jokers",
+            |args| {
+                let cards: String = arg(args, 0, "cards")?;
+                let rank = |hands: &Vec<Hand>, joker_mode| {
+                    let h = hand(&cards, joker_mode);
+                    hands
+                        .iter()
+                        .position(|o| *o == h)
+                        .map_or("not in input".to_owned(), |i| (i + 1).to_string())
+                };
+                Ok(format!(
+                    "{:?}, rank {}\nwith jokers: {:?}, rank {}",
+                    hand(&cards, false).get_hand_type(),
+                    rank(&hands, false),
+                    hand(&cards, true).get_hand_type(),
+                    rank(&joker_hands, true)
+                ))
+            },
+        ),
+        Command::new("compare", "<cards> <cards>", "order two hands", |args| {
+            let a: String = arg(args, 0, "cards")?;
+            let b: String = arg(args, 1, "cards")?;
+            Ok(format!(
+                "{:?}\nwith jokers: {:?}",
+                hand(&a, false).cmp(&hand(&b, false)),
+                hand(&a, true).cmp(&hand(&b, true))
+            ))
+        }),
+    ]);
+}
+
+//higher is stronger: the card counts sorted from most to least common
+fn reference_strength(cards: &[char]) -> Vec<usize> {
+    let mut counts: Vec<usize> = cards
+        .iter()
+        .map(|c| cards.iter().filter(|o| *o == c).count())
+        .collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    counts
+}
+
+//tries every card for every joker independently
+fn reference_best_strength(cards: Vec<char>) -> Vec<usize> {
+    match cards.iter().position(|&c| c == JOKER) {
+        None => reference_strength(&cards),
+        Some(i) => "23456789TQKA"
+            .chars()
+            .map(|c| {
+                let mut replaced = cards.clone();
+                replaced[i] = c;
+                reference_best_strength(replaced)
+            })
+            .max()
+            .unwrap(),
+    }
+}
+
+fn reference_winnings(input: &str, joker_mode: bool) -> usize {
+    let order = if joker_mode {
+        "J23456789TQKA"
+    } else {
+        "23456789TJQKA"
+    };
+    let mut hands: Vec<(Vec<usize>, Vec<usize>, usize)> = input
+        .lines()
+        .map(|line| {
+            let (cards, bid) = line.split_once(' ').unwrap();
+            let cards: Vec<char> = cards.chars().collect();
+            let strength = if joker_mode {
+                reference_best_strength(cards.clone())
+            } else {
+                reference_strength(&cards)
+            };
+            let ranks = cards.iter().map(|&c| order.find(c).unwrap()).collect();
+            (strength, ranks, bid.parse().unwrap())
+        })
+        .collect();
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, _, bid))| (i + 1) * bid)
+        .sum()
+}
+
+pub fn reference1(input: &str) -> usize {
+    reference_winnings(input, false)
+}
+
+pub fn reference2(input: &str) -> usize {
+    reference_winnings(input, true)
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    const CARDS: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+    //equal hands would tie, and a tie makes the winnings depend on sort order
+    let count = (10 * scale).min(CARDS.len().pow(5));
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+    while seen.len() < count {
+        //bias towards repeated cards so every hand type shows up
+        let pool: Vec<char> = (0..rng.range(1..=5)).map(|_| *rng.choose(&CARDS)).collect();
+        let cards: String = (0..5)
+            .map(|_| {
+                if rng.chance(1, 4) {
+                    *rng.choose(&CARDS)
+                } else {
+                    *rng.choose(&pool)
+                }
+            })
+            .collect();
+        if seen.insert(cards.clone()) {
+            out.push_str(&format!("{} {}\n", cards, rng.range(1..=1000)));
+        }
+    }
+    out
+}
@@ -1,7 +1,13 @@
-use std::collections::HashMap;
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::num::checked_lcm_all;
+use crate::helper::rng::Rng;
+use std::collections::{HashMap, HashSet};
 
-const _DAY08_SIMPLE_INPUT: &str = include_str!(r"input\day08_simple.txt");
-const DAY08_INPUT: &str = include_str!(r"input\day08.txt");
+pub const INPUT: &str = include_str!(r"input\day08.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day08_simple.txt")];
+pub const PART1: Variants<usize> = &[("default", part1)];
+pub const PART2: Variants<u128> = &[("default", part2)];
 
 #[derive(Debug)]
 enum Direction {
@@ -151,51 +157,187 @@ fn calculate_ghost_steps(graph: &HashMap<&str, Node>, directions: Vec<Direction>
         }
     }
 
-    walk_states_done
-        .windows(2)
-        .filter_map(|w| TryInto::<&[_; 2]>::try_into(w).ok())
-        .fold(0, |total, [a, b]| {
-            if total == 0 {
-                lcm(
-                    a.loop_length.unwrap() as u128,
-                    b.loop_length.unwrap() as u128,
-                )
-            } else {
-                lcm(total, b.loop_length.unwrap() as u128)
-            }
-        })
+    checked_lcm_all(
+        walk_states_done
+            .iter()
+            .map(|w| w.loop_length.unwrap() as u128),
+    )
+    .expect("answer too large for u128")
 }
 
-fn gcd(mut a: u128, mut b: u128) -> u128 {
-    if a == b {
-        return a;
-    }
+pub fn part1(input: &str) -> usize {
+    let (graph, directions) = read_input(input);
+    calculate_steps(&graph, directions)
+}
 
-    if b > a {
-        let temp = a;
-        a = b;
-        b = temp;
-    }
+pub fn part2(input: &str) -> u128 {
+    let (graph, directions) = read_input(input);
+    calculate_ghost_steps(&graph, directions)
+}
+
+pub fn repl(input: &str) {
+    let (graph, directions) = read_input(input);
+    let node = |args: &[&str]| -> Result<&Node, String> {
+        let name: String = arg(args, 0, "node")?;
+        graph
+            .get(name.as_str())
+            .ok_or_else(|| format!("no node {name}"))
+    };
+    repl::run(vec![
+        Command::new(
+            "step",
+            "<node> <L|R>",
+            "take one step from a node",
+            |args| {
+                let direction = match arg::<char>(args, 1, "direction")? {
+                    'L' => Direction::Left,
+                    'R' => Direction::Right,
+                    d => return Err(format!("unknown direction: {d}")),
+                };
+                Ok(follow_direction(node(args)?, &direction).to_owned())
+            },
+        ),
+        Command::new(
+            "walk",
+            "<node> <steps>",
+            "follow the directions from a node",
+            |args| {
+                let steps: usize = arg(args, 1, "steps")?;
+                let mut current = node(args)?.name;
+                let mut path = vec![current];
+                for d in directions.iter().cycle().take(steps) {
+                    current = follow_direction(get_node(&graph, current), d);
+                    path.push(current);
+                }
+                Ok(path.join(" -> "))
+            },
+        ),
+    ]);
+}
 
-    while b > 0 {
-        let temp = a;
-        a = b;
-        b = temp % b;
+fn reference_network(input: &str) -> (Vec<char>, HashMap<String, (String, String)>) {
+    let mut lines = input.lines();
+    let directions = lines.next().unwrap().chars().collect();
+    let network = lines
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let (name, pair) = l.split_once(" = ").unwrap();
+            let (left, right) = pair
+                .trim_matches(|c| c == '(' || c == ')')
+                .split_once(", ")
+                .unwrap();
+            (name.to_owned(), (left.to_owned(), right.to_owned()))
+        })
+        .collect();
+    (directions, network)
+}
+
+//walks every ghost in lockstep until they all stand on an end at once
+fn reference_walk(input: &str, is_start: fn(&str) -> bool, is_end: fn(&str) -> bool) -> usize {
+    let (directions, network) = reference_network(input);
+    let mut positions: Vec<&String> = network.keys().filter(|n| is_start(n)).collect();
+    assert!(!positions.is_empty(), "no start nodes");
+    let mut steps = 0;
+    for d in directions.iter().cycle() {
+        if positions.iter().all(|p| is_end(p)) {
+            break;
+        }
+        for p in positions.iter_mut() {
+            let (left, right) = &network[*p];
+            *p = if *d == 'L' { left } else { right };
+        }
+        steps += 1;
     }
+    steps
+}
 
-    a
+pub fn reference1(input: &str) -> usize {
+    reference_walk(input, |n| n == "AAA", |n| n == "ZZZ")
 }
 
-fn lcm(a: u128, b: u128) -> u128 {
-    a * (b / gcd(a, b))
+pub fn reference2(input: &str) -> u128 {
+    reference_walk(input, |n| n.ends_with('A'), |n| n.ends_with('Z')) as u128
 }
 
-pub fn part1() -> usize {
-    let (graph, directions) = read_input(DAY08_INPUT);
-    calculate_steps(&graph, directions)
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    const NAME_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    const PRIMES: [usize; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+    let directions: Vec<usize> = (0..rng.range(2..=(2 + 4 * scale).min(300)))
+        .map(|_| rng.range(0..2))
+        .collect();
+    let period = directions.len();
+
+    let mut names: Vec<String> = Vec::new();
+    let mut used = HashSet::new();
+    let mut add_name = |names: &mut Vec<String>, name: String| -> bool {
+        if used.insert(name.clone()) {
+            names.push(name);
+            return true;
+        }
+        false
+    };
+    //[left, right] as indexes into names, filled in with random nodes if unset
+    let mut edges: Vec<[Option<usize>; 2]> = Vec::new();
+
+    //each ghost walks start -> ... -> end in a multiple of the directions length, then
+    //loops back to the node after its start, which is the shape the lcm solution relies on
+    let mut cycles = PRIMES;
+    rng.shuffle(&mut cycles);
+    for (ghost, cycle) in cycles.into_iter().take(2 + scale.min(4)).enumerate() {
+        let length = period * cycle;
+        let first = names.len();
+        if ghost == 0 {
+            add_name(&mut names, "AAA".to_owned());
+        } else {
+            while !add_name(&mut names, random_name(rng, NAME_CHARS, b'A')) {}
+        }
+        for _ in 1..length {
+            let last = *rng.choose(&NAME_CHARS[1..25]);
+            while !add_name(&mut names, random_name(rng, NAME_CHARS, last)) {}
+        }
+        if ghost == 0 {
+            add_name(&mut names, "ZZZ".to_owned());
+        } else {
+            while !add_name(&mut names, random_name(rng, NAME_CHARS, b'Z')) {}
+        }
+        for i in 0..=length {
+            let next = if i == length {
+                first + 1
+            } else {
+                first + i + 1
+            };
+            let mut edge = [None, None];
+            edge[directions[i % period]] = Some(next);
+            edges.push(edge);
+        }
+    }
+
+    for _ in 0..(10 * scale).min(5000) {
+        let last = *rng.choose(&NAME_CHARS[1..25]);
+        if add_name(&mut names, random_name(rng, NAME_CHARS, last)) {
+            edges.push([None, None]);
+        }
+    }
+
+    let mut lines: Vec<String> = edges
+        .iter()
+        .enumerate()
+        .map(|(i, edge)| {
+            let [left, right] =
+                edge.map(|e| &names[e.unwrap_or_else(|| rng.range(0..names.len()))]);
+            format!("{} = ({}, {})", names[i], left, right)
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+
+    let directions: String = directions
+        .iter()
+        .map(|&d| if d == 0 { 'L' } else { 'R' })
+        .collect();
+    format!("{}\n\n{}\n", directions, lines.join("\n"))
 }
 
-pub fn part2() -> u128 {
-    let (graph, directions) = read_input(DAY08_INPUT);
-    calculate_ghost_steps(&graph, directions)
+fn random_name(rng: &mut Rng, chars: &[u8], last: u8) -> String {
+    String::from_utf8(vec![*rng.choose(chars), *rng.choose(chars), last]).unwrap()
 }
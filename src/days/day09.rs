@@ -1,117 +1,154 @@
-use std::ops::{Add, Sub};
-
-const _DAY09_SIMPLE_INPUT: &str = include_str!(r"input\day09_simple.txt");
-const DAY09_INPUT: &str = include_str!(r"input\day09.txt");
-
-mod history {
-    use std::marker::PhantomData;
-
-    type HistoryItems = Vec<Vec<i32>>;
-
-    pub struct New;
-    pub struct Ran;
-    pub struct History<State> {
-        pub items: HistoryItems,
-        state: PhantomData<State>,
-    }
-
-    impl History<New> {
-        pub fn new(line: &str) -> Self {
-            let mut items = Vec::new();
-
-            let values = line
-                .split(" ")
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::poly::{degree, difference_table, extrapolate, lagrange};
+use crate::helper::rng::Rng;
+
+pub const INPUT: &str = include_str!(r"input\day09.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day09_simple.txt")];
+pub const PART1: Variants<i32> = &[("default", part1), ("lagrange", part1_lagrange)];
+pub const PART2: Variants<i32> = &[("default", part2), ("lagrange", part2_lagrange)];
+
+fn get_histories(input: &str) -> Vec<Vec<i64>> {
+    input
+        .lines()
+        .map(|l| {
+            l.split(" ")
                 .map(|v| v.parse().expect("could not parse number"))
-                .collect();
-
-            items.push(values);
-
-            History {
-                items,
-                state: PhantomData,
-            }
-        }
-
-        pub fn was_ran(self) -> History<Ran> {
-            History {
-                items: self.items,
-                state: PhantomData,
-            }
-        }
-    }
+                .collect()
+        })
+        .collect()
 }
 
-use history::*;
-
-fn get_histories(input: &str) -> Vec<History<New>> {
-    input.lines().map(|l| History::new(l)).collect()
+//value at offset, counted from the first in each history, added up over every history
+fn extrapolate_all(
+    input: &str,
+    method: fn(&[i64], i64) -> Result<i64, String>,
+    offset: impl Fn(&[i64]) -> i64,
+) -> i32 {
+    let total: i64 = get_histories(input)
+        .iter()
+        .map(|h| method(h, offset(h)).unwrap_or_else(|e| panic!("bad history: {e}")))
+        .sum();
+    total.try_into().expect("answer too large for i32")
 }
 
-fn iterate_history(mut history: History<New>) -> History<New> {
-    let last = history
-        .items
-        .last()
-        .expect("could not find line to iterate");
-
-    let next = last
-        .windows(2)
-        .filter_map(|w| TryInto::<&[_; 2]>::try_into(w).ok())
-        .map(|[a, b]| b - a)
-        .collect();
-
-    history.items.push(next);
+pub fn part1(input: &str) -> i32 {
+    extrapolate_all(input, extrapolate, |h| h.len() as i64)
+}
 
-    return history;
+pub fn part2(input: &str) -> i32 {
+    extrapolate_all(input, extrapolate, |_| -1)
 }
 
-fn run_history(mut history: History<New>) -> History<Ran> {
-    while !history.items.last().unwrap().iter().all(|&i| i == 0) {
-        history = iterate_history(history);
-    }
+pub fn part1_lagrange(input: &str) -> i32 {
+    extrapolate_all(input, lagrange, |h| h.len() as i64)
+}
 
-    history.was_ran()
+pub fn part2_lagrange(input: &str) -> i32 {
+    extrapolate_all(input, lagrange, |_| -1)
 }
 
-fn extrapolate(history: &History<Ran>, forwards: bool) -> i32 {
-    history
-        .items
-        .iter()
-        .rev()
-        .skip(1) // skip all 0s line
-        .fold(
-            0,
-            if forwards {
-                forwards_total
-            } else {
-                backwards_total
+pub fn repl(input: &str) {
+    let histories = get_histories(input);
+    let history = |args: &[&str]| -> Result<&Vec<i64>, String> {
+        let n: usize = arg(args, 0, "line number")?;
+        histories
+            .get(n.wrapping_sub(1))
+            .ok_or_else(|| format!("no line {n}"))
+    };
+    repl::run(vec![
+        Command::new(
+            "history",
+            "<n>",
+            "show the difference table for line n",
+            |args| {
+                let history = history(args)?;
+                let mut out: Vec<String> = difference_table(history)?
+                    .iter()
+                    .enumerate()
+                    .map(|(depth, row)| {
+                        let row: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+                        format!("{}{}", " ".repeat(depth * 2), row.join(" "))
+                    })
+                    .collect();
+                out.push(format!(
+                    "degree {}, previous {}, next {}",
+                    degree(history)?,
+                    extrapolate(history, -1)?,
+                    extrapolate(history, history.len() as i64)?
+                ));
+                Ok(out.join("\n"))
+            },
+        ),
+        Command::new(
+            "at",
+            "<n> <offset>",
+            "extrapolate line n to any offset, 0 being its first value",
+            |args| {
+                let offset: i64 = arg(args, 1, "offset")?;
+                Ok(extrapolate(history(args)?, offset)?.to_string())
             },
-        )
+        ),
+    ]);
 }
 
-fn forwards_total<T>(total: T, iter: &Vec<T>) -> T
-where
-    T: Add<T, Output = T> + Copy,
-{
-    total + *iter.last().unwrap()
+fn reference_next(values: &[i32]) -> i32 {
+    if values.iter().all(|&v| v == 0) {
+        return 0;
+    }
+    let differences: Vec<i32> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    values.last().unwrap() + reference_next(&differences)
 }
 
-fn backwards_total<T>(total: T, iter: &Vec<T>) -> T
-where
-    T: Sub<Output = T> + Copy,
-{
-    *iter.first().unwrap() - total
+fn reference_sequences(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|l| l.split_whitespace().map(|v| v.parse().unwrap()).collect())
+        .collect()
 }
 
-pub fn part1() -> i32 {
-    get_histories(DAY09_INPUT)
-        .into_iter()
-        .map(|h| extrapolate(&run_history(h), true))
+pub fn reference1(input: &str) -> i32 {
+    reference_sequences(input)
+        .iter()
+        .map(|s| reference_next(s))
         .sum()
 }
 
-pub fn part2() -> i32 {
-    get_histories(DAY09_INPUT)
+//extrapolating backwards is extrapolating the reversed sequence forwards
+pub fn reference2(input: &str) -> i32 {
+    reference_sequences(input)
         .into_iter()
-        .map(|h| extrapolate(&run_history(h), false))
+        .map(|mut s| {
+            s.reverse();
+            reference_next(&s)
+        })
         .sum()
 }
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..10 * scale {
+        //sum of c_k * C(x, k) is integer valued for any integer coefficients,
+        //and its differences reach zero after degree + 1 rows
+        let degree = rng.range(0..=6);
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.range(-10..=10)).collect();
+        let len = rng.range(degree + 2..=21);
+        let values: Vec<String> = (0..len as i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(k, c)| c * binomial(x, k as i64))
+                    .sum::<i64>()
+                    .to_string()
+            })
+            .collect();
+        out.push_str(&values.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+fn binomial(n: i64, k: i64) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
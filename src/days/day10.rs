@@ -1,229 +1,506 @@
-const _DAY10_SIMPLE_INPUT: &str = include_str!(r"input\day10_simple.txt");
-const DAY10_INPUT: &str = include_str!(r"input\day10.txt");
+pub const INPUT: &str = include_str!(r"input\day10.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day10_simple.txt")];
+pub const PART1: Variants<i32> = &[("default", part1)];
+pub const PART2: Variants<usize> = &[("default", part2), ("shoelace", part2_shoelace)];
 
 type Vec2 = crate::helper::vec2::Vec2<i32>;
+type Vec2I64 = crate::helper::vec2::Vec2<i64>;
 mod pipes;
 mod pipemap {
     use super::pipes::Pipes;
+    use crate::helper::direction::Dir4;
+    use crate::helper::grid::Grid;
     type Vec2 = crate::helper::vec2::Vec2<i32>;
 
     #[derive(Debug)]
     pub struct PipeMap {
-        map: Vec<Pipes>,
-        width: i32,
-        height: i32,
+        map: Grid<Pipes>,
+        start: Vec2,
     }
 
     impl PipeMap {
         pub fn new(input: &str) -> Self {
-            let width = input
-                .lines()
-                .next()
-                .expect("could not find first line")
-                .len()
-                .try_into()
-                .expect("width does not fit in i32");
-            let mut height = 0;
-            let map = input
-                .lines()
-                .flat_map(|l| {
-                    height += 1;
-                    l.chars()
-                })
-                .map(|c| match c {
-                    '|' => Pipes::NORTH | Pipes::SOUTH,
-                    '-' => Pipes::EAST | Pipes::WEST,
-                    'L' => Pipes::NORTH | Pipes::EAST,
-                    'J' => Pipes::NORTH | Pipes::WEST,
-                    '7' => Pipes::SOUTH | Pipes::WEST,
-                    'F' => Pipes::SOUTH | Pipes::EAST,
-                    'S' => Pipes::START,
-                    '.' => Pipes::empty(),
-                    _ => panic!("unknown pipe type: {}", c),
-                })
-                .collect();
-            let result = PipeMap { map, width, height };
-            let _test: i32 = result
-                .map
-                .len()
+            let (map, marks) = Grid::parse_marked(input, "S", |_, c| match c {
+                '|' => Ok(Pipes::NORTH | Pipes::SOUTH),
+                '-' => Ok(Pipes::EAST | Pipes::WEST),
+                'L' => Ok(Pipes::NORTH | Pipes::EAST),
+                'J' => Ok(Pipes::NORTH | Pipes::WEST),
+                '7' => Ok(Pipes::SOUTH | Pipes::WEST),
+                'F' => Ok(Pipes::SOUTH | Pipes::EAST),
+                'S' => Ok(Pipes::START),
+                '.' => Ok(Pipes::empty()),
+                _ => Err(format!("unknown pipe type: {}", c)),
+            })
+            .unwrap_or_else(|e| panic!("could not parse pipe map: {e}"));
+            let _test: i32 = (map.width() * map.height())
                 .try_into()
                 .expect("map too big to fit in i32");
-            return result;
+            let start = match marks.get(&'S').map(|s| s.as_slice()) {
+                Some(&[start]) => start,
+                Some(_) => panic!("found more than one start"),
+                None => panic!("could not find start"),
+            };
+            PipeMap { map, start }
         }
 
         pub fn get_pipes(&self, point: Vec2) -> Pipes {
-            self.get_with_index(self.to_index(point))
+            match self.map.get(point) {
+                Some(p) if p.contains(Pipes::START) => self.get_pipes_for_start(point),
+                Some(&p) => p,
+                None => Pipes::empty(),
+            }
+        }
+
+        pub fn grid(&self) -> &Grid<Pipes> {
+            &self.map
         }
 
         pub fn get_dimensions(&self) -> Vec2 {
-            return Vec2::new(self.width, self.height);
+            return self.map.size();
         }
 
         fn get_pipes_for_start(&self, point: Vec2) -> Pipes {
-            Vec2::ALL_CARDINAL
-                .iter()
-                .filter_map(|&d| {
-                    self.get_pipes(point + d)
-                        .contains(d.invert().as_pipe())
-                        .then_some(d.as_pipe())
+            Dir4::ALL
+                .into_iter()
+                .filter_map(|d| {
+                    self.get_pipes(point + Vec2::from(d))
+                        .contains(d.reverse().into())
+                        .then_some(d.into())
                 })
                 .fold(Pipes::empty(), |out, p| out | p)
         }
 
         pub fn find_start(&self) -> Vec2 {
-            self.from_index(
-                self.map
-                    .iter()
-                    .position(|&p| p == Pipes::START)
-                    .expect("could not find start")
-                    .try_into()
-                    .unwrap(),
-            )
-        }
-
-        pub fn get_with_index(&self, index: i32) -> Pipes {
-            if index < 0 || index >= self.map.len().try_into().unwrap() {
-                Pipes::empty()
-            } else {
-                let out = *self.map.get::<usize>(index.try_into().unwrap()).unwrap();
-                if out.contains(Pipes::START) {
-                    self.get_pipes_for_start(self.from_index(index))
-                } else {
-                    out
-                }
-            }
-        }
-
-        fn to_index(&self, point: Vec2) -> i32 {
-            if point.x < 0 || point.x >= self.width || point.y < 0 || point.y >= self.height {
-                -1
-            } else {
-                point.x + point.y * self.width
-            }
-        }
-
-        fn from_index(&self, index: i32) -> Vec2 {
-            Vec2 {
-                x: index % self.width,
-                y: index / self.width,
-            }
+            self.start
         }
     }
 }
 
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::bit_grid::BitGrid;
+use crate::helper::direction::Dir4;
+use crate::helper::flood::{flood_fill, Lattice};
+use crate::helper::polygon::interior_points;
+use crate::helper::render::{Color, Renderer};
+use crate::helper::rng::Rng;
+use crate::helper::search::{astar, bfs};
 use pipemap::*;
 use pipes::Pipes;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
-type Visited = HashMap<Vec2, i32>;
+type Visited = HashMap<Vec2, usize>;
 
+//distance along the loop to every tile on it, and the tile furthest away
 fn visit_loop(pipemap: &PipeMap, start: Vec2) -> (Visited, Vec2) {
-    let mut visited = Visited::new();
-    visited.insert(start, 0);
-    let mut queue = VecDeque::<Vec2>::new();
-    let mut last = start;
-    queue.push_back(start);
-    loop {
-        let Some(current) = queue.pop_front() else {
-            break;
-        };
-        let depth = *visited
-            .get(&current)
-            .expect("must already be visited to be in queue");
-        for adj in pipemap.get_pipes(current) {
-            let next = adj + current;
-            if !visited.contains_key(&next) {
-                visited.insert(next, depth + 1);
-                last = adj + current;
-                queue.push_back(next)
-            }
-        }
-    }
+    let visited = bfs(start, |&current| {
+        pipemap
+            .get_pipes(current)
+            .into_iter()
+            .map(move |adj| current + adj)
+    })
+    .distances;
+    let last = *visited
+        .iter()
+        .max_by_key(|(_, &depth)| depth)
+        .expect("start is always visited")
+        .0;
     (visited, last)
 }
 
-pub fn part1() -> i32 {
-    let pipemap = PipeMap::new(DAY10_INPUT);
+pub fn part1(input: &str) -> i32 {
+    let pipemap = PipeMap::new(input);
     let start = pipemap.find_start();
     let (visited, last) = visit_loop(&pipemap, start);
-    *visited.get(&last).unwrap()
+    *visited.get(&last).unwrap() as i32
 }
 
 //assume we're at top left corner of square e.g. we travel on the grids lines rather than in the grid squares
 //so we can "squeeze" through pipes
-fn flood_fill(pipemap: &PipeMap, visited: &Visited) -> HashSet<Vec2> {
-    let start = Vec2::new(0, 0);
-    let mut flooded = HashSet::new();
-    let mut queue = VecDeque::<Vec2>::new();
-    flooded.insert(start);
-    queue.push_back(start);
-    let map_size = pipemap.get_dimensions() + Vec2::new(1, 1);
-    loop {
-        let Some(current) = queue.pop_front() else {
-            break;
-        };
-        for dir in Vec2::ALL_CARDINAL {
-            let next = current + *dir;
-
-            if !is_in_map(&next, &map_size)
-                || flooded.contains(&next)
-                || blocked_by_pipes(dir, current, pipemap, visited)
-            {
-                continue;
-            }
-
-            flooded.insert(next);
-            queue.push_back(next);
-        }
-    }
-
-    return flooded;
+fn flood_outside(pipemap: &PipeMap, on_loop: &BitGrid) -> BitGrid {
+    flood_fill(
+        [Vec2::new(0, 0)],
+        pipemap.get_dimensions(),
+        Lattice::Corners,
+        |from, to| !blocked_by_pipes(&(to - from), from, pipemap, on_loop),
+    )
 }
 
-fn blocked_by_pipes(
-    dir: &Vec2,
-    current: Vec2,
-    pipemap: &PipeMap,
-    visited: &HashMap<Vec2, i32>,
-) -> bool {
+fn blocked_by_pipes(dir: &Vec2, current: Vec2, pipemap: &PipeMap, on_loop: &BitGrid) -> bool {
     match *dir {
         Vec2::NORTH => {
-            get_pipes_if_visited(&(current + *dir), pipemap, visited).contains(Pipes::WEST)
+            get_pipes_if_on_loop(&(current + *dir), pipemap, on_loop).contains(Pipes::WEST)
         }
-        Vec2::SOUTH => get_pipes_if_visited(&(current), pipemap, visited).contains(Pipes::WEST),
+        Vec2::SOUTH => get_pipes_if_on_loop(&(current), pipemap, on_loop).contains(Pipes::WEST),
         Vec2::WEST => {
-            get_pipes_if_visited(&(current + *dir), pipemap, visited).contains(Pipes::NORTH)
+            get_pipes_if_on_loop(&(current + *dir), pipemap, on_loop).contains(Pipes::NORTH)
         }
-        Vec2::EAST => get_pipes_if_visited(&(current), pipemap, visited).contains(Pipes::NORTH),
+        Vec2::EAST => get_pipes_if_on_loop(&(current), pipemap, on_loop).contains(Pipes::NORTH),
         _ => panic!(),
     }
 }
 
-fn get_pipes_if_visited(pos: &Vec2, pipemap: &PipeMap, visited: &Visited) -> Pipes {
-    match visited.contains_key(pos) {
+fn get_pipes_if_on_loop(pos: &Vec2, pipemap: &PipeMap, on_loop: &BitGrid) -> Pipes {
+    match on_loop.get(*pos) {
         true => pipemap.get_pipes(*pos),
         false => Pipes::empty(),
     }
 }
 
-fn is_in_map(pos: &Vec2, map_size: &Vec2) -> bool {
-    pos.x >= 0 && pos.x < map_size.x && pos.y >= 0 && pos.y < map_size.y
+pub fn part2(input: &str) -> usize {
+    let pipemap = PipeMap::new(input);
+    let start = pipemap.find_start();
+    let (visited, _) = visit_loop(&pipemap, start);
+    let on_loop = loop_tiles(&pipemap, &visited);
+    let flooded = flood_outside(&pipemap, &on_loop);
+    pipemap
+        .grid()
+        .positions()
+        .filter(|&pos| !flooded.get(pos) && !on_loop.get(pos))
+        .count()
+}
+
+fn loop_tiles(pipemap: &PipeMap, visited: &Visited) -> BitGrid {
+    let mut on_loop = BitGrid::new(pipemap.grid().width(), pipemap.grid().height());
+    for &pos in visited.keys() {
+        on_loop.set(pos, true);
+    }
+    on_loop
+}
+
+//the loop in walking order, starting at start
+fn walk_loop(pipemap: &PipeMap, start: Vec2) -> Vec<Vec2> {
+    let mut path = vec![start];
+    let mut previous = start;
+    let mut current = start
+        + pipemap
+            .get_pipes(start)
+            .iter()
+            .next()
+            .expect("start is not connected");
+    while current != start {
+        let next = pipemap
+            .get_pipes(current)
+            .iter()
+            .map(|p| current + p)
+            .find(|&n| n != previous)
+            .expect("loop is broken");
+        path.push(current);
+        previous = current;
+        current = next;
+    }
+    path
 }
 
-pub fn part2() -> usize {
-    let pipemap = PipeMap::new(DAY10_INPUT);
+//shoelace gives the loop's area, and pick's theorem turns that into the tiles inside it
+pub fn part2_shoelace(input: &str) -> usize {
+    let pipemap = PipeMap::new(input);
+    let path: Vec<_> = walk_loop(&pipemap, pipemap.find_start())
+        .into_iter()
+        .map(Vec2I64::from)
+        .collect();
+    interior_points(&path) as usize
+}
+
+pub fn repl(input: &str) {
+    let pipemap = PipeMap::new(input);
     let start = pipemap.find_start();
     let (visited, _) = visit_loop(&pipemap, start);
-    let flooded = flood_fill(&pipemap, &visited);
-    let dimensions = pipemap.get_dimensions();
+    let on_loop = loop_tiles(&pipemap, &visited);
+    let flooded = flood_outside(&pipemap, &on_loop);
+    repl::run(vec![
+        Command::new(
+            "show",
+            "[<x> <y> <width> <height>]",
+            "draw the map with the loop, O outside it and I inside",
+            |args| {
+                let mut renderer = Renderer::new(pipemap.grid(), |&p| match p {
+                    p if p.is_empty() => '.',
+                    Pipes::START => 'S',
+                    p => pipe_char(p),
+                })
+                .overlay(|p| on_loop.get(p), None, Some(Color::Green))
+                .overlay(
+                    |p| !on_loop.get(p) && flooded.get(p),
+                    Some('O'),
+                    Some(Color::Blue),
+                )
+                .overlay(
+                    |p| !on_loop.get(p) && !flooded.get(p),
+                    Some('I'),
+                    Some(Color::Yellow),
+                );
+                if !args.is_empty() {
+                    let min = Vec2::new(arg(args, 0, "x")?, arg(args, 1, "y")?);
+                    let size = Vec2::new(arg(args, 2, "width")?, arg(args, 3, "height")?);
+                    renderer = renderer.window(min, min + size - Vec2::new(1, 1));
+                }
+                Ok(renderer.render())
+            },
+        ),
+        Command::new(
+            "region",
+            "<x> <y>",
+            "mark the tiles off the loop a tile connects to without squeezing past pipes",
+            |args| {
+                let pos = Vec2::new(arg(args, 0, "x")?, arg(args, 1, "y")?);
+                if pipemap.grid().get(pos).is_none() || on_loop.get(pos) {
+                    return Err(format!("{},{} is not a tile off the loop", pos.x, pos.y));
+                }
+                let region =
+                    flood_fill([pos], pipemap.get_dimensions(), Lattice::Cells, |_, to| {
+                        !on_loop.get(to)
+                    });
+                let map = Renderer::new(pipemap.grid(), |_| '.')
+                    .overlay(|p| on_loop.get(p), Some('*'), Some(Color::Green))
+                    .overlay(|p| region.get(p), Some('#'), Some(Color::Magenta))
+                    .render();
+                Ok(format!("{map}{} tiles", region.count_ones()))
+            },
+        ),
+        Command::new("start", "", "show where the loop starts", |_| {
+            Ok(format!("{:?} {:?}", start, pipemap.get_pipes(start)))
+        }),
+        Command::new(
+            "pipe",
+            "<x> <y>",
+            "show a tile's pipes and its distance along the loop",
+            |args| {
+                let pos = Vec2::new(arg(args, 0, "x")?, arg(args, 1, "y")?);
+                Ok(format!(
+                    "{:?}\nloop distance: {}",
+                    pipemap.get_pipes(pos),
+                    visited
+                        .get(&pos)
+                        .map_or("not on loop".to_owned(), |d| d.to_string())
+                ))
+            },
+        ),
+        Command::new(
+            "path",
+            "<x> <y>",
+            "show the shortest way along the loop from the start to a tile",
+            |args| {
+                let goal = Vec2::new(arg(args, 0, "x")?, arg(args, 1, "y")?);
+                let (search, found) = astar(
+                    start,
+                    |&current| {
+                        pipemap
+                            .get_pipes(current)
+                            .into_iter()
+                            .map(move |adj| (current + adj, 1))
+                    },
+                    |p| p.manhattan(goal),
+                    |&p| p == goal,
+                );
+                let path = found
+                    .and_then(|g| search.path_to(&g))
+                    .ok_or("not on loop")?;
+                let steps: Vec<String> = path.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+                Ok(format!("{} steps: {}", path.len() - 1, steps.join(" ")))
+            },
+        ),
+    ]);
+}
+
+//(dx, dy) offsets a pipe character connects to
+fn reference_connections(c: char) -> Vec<(i32, i32)> {
+    match c {
+        '|' => vec![(0, -1), (0, 1)],
+        '-' => vec![(1, 0), (-1, 0)],
+        'L' => vec![(0, -1), (1, 0)],
+        'J' => vec![(0, -1), (-1, 0)],
+        '7' => vec![(0, 1), (-1, 0)],
+        'F' => vec![(0, 1), (1, 0)],
+        _ => vec![],
+    }
+}
+
+//the grid with S replaced by its real pipe, and every cell on the loop
+fn reference_loop(input: &str) -> (Vec<Vec<char>>, HashSet<(i32, i32)>) {
+    let mut grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let get = |grid: &Vec<Vec<char>>, x: i32, y: i32| {
+        usize::try_from(y)
+            .ok()
+            .and_then(|y| grid.get(y))
+            .and_then(|row| usize::try_from(x).ok().and_then(|x| row.get(x)))
+            .copied()
+            .unwrap_or('.')
+    };
+    let (sx, sy) = (0..grid.len())
+        .flat_map(|y| (0..grid[y].len()).map(move |x| (x, y)))
+        .find(|&(x, y)| grid[y][x] == 'S')
+        .map(|(x, y)| (x as i32, y as i32))
+        .unwrap();
+    let start_connections: Vec<(i32, i32)> = [(0, -1), (0, 1), (1, 0), (-1, 0)]
+        .into_iter()
+        .filter(|&(dx, dy)| {
+            reference_connections(get(&grid, sx + dx, sy + dy)).contains(&(-dx, -dy))
+        })
+        .collect();
+    grid[sy as usize][sx as usize] = "|-LJ7F"
+        .chars()
+        .find(|&c| {
+            let mut a = reference_connections(c);
+            let mut b = start_connections.clone();
+            a.sort();
+            b.sort();
+            a == b
+        })
+        .unwrap();
+
+    let mut on_loop = HashSet::from([(sx, sy)]);
+    let (mut x, mut y) = (sx, sy);
+    let (mut dx, mut dy) = start_connections[0];
+    loop {
+        x += dx;
+        y += dy;
+        if (x, y) == (sx, sy) {
+            break;
+        }
+        on_loop.insert((x, y));
+        (dx, dy) = reference_connections(get(&grid, x, y))
+            .into_iter()
+            .find(|&d| d != (-dx, -dy))
+            .unwrap();
+    }
+    (grid, on_loop)
+}
+
+pub fn reference1(input: &str) -> i32 {
+    let (_, on_loop) = reference_loop(input);
+    on_loop.len() as i32 / 2
+}
+
+//scans each row left to right; crossing a loop pipe that reaches north flips inside and out
+pub fn reference2(input: &str) -> usize {
+    let (grid, on_loop) = reference_loop(input);
     let mut count = 0;
-    for y in 0..dimensions.y {
-        for x in 0..dimensions.x {
-            let pos = Vec2::new(x, y);
-            if !flooded.contains(&pos) && !visited.contains_key(&pos) {
-                count += 1
+    for (y, row) in grid.iter().enumerate() {
+        let mut inside = false;
+        for (x, &c) in row.iter().enumerate() {
+            if on_loop.contains(&(x as i32, y as i32)) {
+                if matches!(c, '|' | 'L' | 'J') {
+                    inside = !inside;
+                }
+            } else if inside {
+                count += 1;
             }
         }
     }
     count
 }
+
+//the loop is the walk around a random spanning tree on a coarse grid, where every coarse
+//cell is a 3x3 ring of pipes and every tree edge splices two rings together. the centre
+//of each ring ends up enclosed, and coarse cells the tree doesn't reach are left as junk.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let coarse = (2 + 2 * scale) as i32;
+    let size = coarse * 3;
+    let index = |v: Vec2| (v.x + v.y * size) as usize;
+    let in_bounds = |v: Vec2| v.x >= 0 && v.y >= 0 && v.x < coarse && v.y < coarse;
+
+    let start = Vec2::new(rng.range(0..coarse), rng.range(0..coarse));
+    let mut in_tree = HashSet::from([start]);
+    let mut frontier = vec![start];
+    let mut tree_edges = Vec::new();
+    let target = (coarse * coarse) as usize * 3 / 5;
+    while in_tree.len() < target && !frontier.is_empty() {
+        let i = rng.range(0..frontier.len());
+        let from = frontier[i];
+        let open: Vec<Vec2> = Vec2::ALL_CARDINAL
+            .iter()
+            .map(|&d| from + d)
+            .filter(|&n| in_bounds(n) && !in_tree.contains(&n))
+            .collect();
+        if open.is_empty() {
+            frontier.swap_remove(i);
+            continue;
+        }
+        let to = *rng.choose(&open);
+        in_tree.insert(to);
+        frontier.push(to);
+        tree_edges.push((from, to));
+    }
+
+    let mut links = vec![Pipes::empty(); (size * size) as usize];
+    let mut link = |a: Vec2, b: Vec2, connect: bool| {
+        let d = Dir4::try_from(b - a).expect("only neighbours can be linked");
+        links[index(a)].set(d.into(), connect);
+        links[index(b)].set(d.reverse().into(), connect);
+    };
+    let cell = |c: Vec2, x: i32, y: i32| c * 3 + Vec2::new(x, y);
+    let ring = [
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (2, 1),
+        (2, 2),
+        (1, 2),
+        (0, 2),
+        (0, 1),
+    ];
+    for &c in &in_tree {
+        for (i, &(x, y)) in ring.iter().enumerate() {
+            let (nx, ny) = ring[(i + 1) % ring.len()];
+            link(cell(c, x, y), cell(c, nx, ny), true);
+        }
+    }
+    for &(from, to) in &tree_edges {
+        let (a, b) = if to.x < from.x || to.y < from.y {
+            (to, from)
+        } else {
+            (from, to)
+        };
+        if a.y == b.y {
+            link(cell(a, 2, 0), cell(a, 2, 1), false);
+            link(cell(b, 0, 0), cell(b, 0, 1), false);
+            link(cell(a, 2, 0), cell(b, 0, 0), true);
+            link(cell(a, 2, 1), cell(b, 0, 1), true);
+        } else {
+            link(cell(a, 0, 2), cell(a, 1, 2), false);
+            link(cell(b, 0, 0), cell(b, 1, 0), false);
+            link(cell(a, 0, 2), cell(b, 0, 0), true);
+            link(cell(a, 1, 2), cell(b, 1, 0), true);
+        }
+    }
+
+    let loop_cells: Vec<Vec2> = (0..size)
+        .flat_map(|y| (0..size).map(move |x| Vec2::new(x, y)))
+        .filter(|&v| !links[index(v)].is_empty())
+        .collect();
+    let s = *rng.choose(&loop_cells);
+
+    let mut out = String::new();
+    for y in 0..size {
+        for x in 0..size {
+            let pos = Vec2::new(x, y);
+            let pipes = links[index(pos)];
+            let c = if pos == s {
+                'S'
+            } else if !pipes.is_empty() {
+                pipe_char(pipes)
+            } else if (pos.x - s.x).abs() + (pos.y - s.y).abs() == 1 || rng.chance(1, 3) {
+                //junk next to S could look like it connects to the start
+                '.'
+            } else {
+                *rng.choose(&['|', '-', 'L', 'J', '7', 'F'])
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn pipe_char(pipes: Pipes) -> char {
+    match pipes {
+        p if p == Pipes::NORTH | Pipes::SOUTH => '|',
+        p if p == Pipes::EAST | Pipes::WEST => '-',
+        p if p == Pipes::NORTH | Pipes::EAST => 'L',
+        p if p == Pipes::NORTH | Pipes::WEST => 'J',
+        p if p == Pipes::SOUTH | Pipes::WEST => '7',
+        p if p == Pipes::SOUTH | Pipes::EAST => 'F',
+        p => panic!("not a pipe: {:?}", p),
+    }
+}
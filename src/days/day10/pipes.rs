@@ -1,3 +1,4 @@
+use crate::helper::direction::Dir4;
 use crate::helper::vec2::Vec2;
 use std::ops::Add;
 
@@ -13,18 +14,28 @@ bitflags! {
     }
 }
 
-impl Vec2<i32> {
-    pub fn as_pipe(&self) -> Pipes {
-        match *self {
-            Self::NORTH => Pipes::NORTH,
-            Self::SOUTH => Pipes::SOUTH,
-            Self::EAST => Pipes::EAST,
-            Self::WEST => Pipes::WEST,
-            _ => panic!(),
+impl From<Dir4> for Pipes {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::North => Pipes::NORTH,
+            Dir4::East => Pipes::EAST,
+            Dir4::South => Pipes::SOUTH,
+            Dir4::West => Pipes::WEST,
         }
     }
 }
 
+impl TryFrom<Pipes> for Dir4 {
+    type Error = String;
+
+    fn try_from(pipes: Pipes) -> Result<Self, Self::Error> {
+        Dir4::ALL
+            .into_iter()
+            .find(|&d| Pipes::from(d) == pipes)
+            .ok_or_else(|| format!("{pipes:?} is not a single direction"))
+    }
+}
+
 impl Add<Pipes> for Vec2<i32> {
     type Output = Self;
 
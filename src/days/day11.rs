@@ -1,17 +1,21 @@
 use crate::days::day11::universe::Universe;
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::rng::Rng;
+use crate::helper::vec2::Vec2;
 
-use self::universe::ManhattanDistance;
-
-const DAY11_INPUT: &str = include_str!(r"input\day11.txt");
-const _DAY11_INPUT_SIMPLE: &str = include_str!(r"input\day11_simple.txt");
+pub const INPUT: &str = include_str!(r"input\day11.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day11_simple.txt")];
+pub const PART1: Variants<i64> = &[("default", part1)];
+pub const PART2: Variants<i64> = &[("default", part2)];
 
 mod universe {
     type Vec2 = crate::helper::vec2::Vec2<i64>;
-    use std::collections::HashSet;
+    use crate::helper::point_set::PointSet;
 
     #[derive(Debug)]
     pub struct Universe {
-        map: HashSet<Vec2>,
+        map: PointSet,
     }
 
     impl Universe {
@@ -26,51 +30,29 @@ mod universe {
             Universe { map }
         }
 
+        pub fn map(&self) -> &PointSet {
+            &self.map
+        }
+
         pub fn get_galaxies(&self) -> impl Iterator<Item = &Vec2> {
             self.map.iter()
         }
 
         pub fn expand(&mut self, expanded_size: i64) {
-            let (by_x, by_y): (HashSet<_>, HashSet<_>) =
-                self.map.iter().map(|v| (v.x, v.y)).unzip();
-
-            let mut rows: Vec<_> = by_y.iter().map(|i| *i).collect();
-            rows.sort_unstable();
-            let rows = rows;
-
-            let mut columns: Vec<_> = by_x.iter().map(|i| *i).collect();
-            columns.sort_unstable();
-            let columns = columns;
-
-            let mut expanded_map = HashSet::new();
-            for galaxy in &self.map {
-                let galaxies_left_and_above = Vec2::new(
-                    columns.partition_point(|&x| x < galaxy.x) as i64,
-                    rows.partition_point(|&y| y < galaxy.y) as i64,
-                );
-
-                let empty_left_and_above = *galaxy - galaxies_left_and_above;
-
-                expanded_map.insert(*galaxy + empty_left_and_above * (expanded_size - 1));
-            }
-
-            self.map = expanded_map;
+            self.map = self.map.stretch(Vec2::new(0, 0), expanded_size);
         }
-    }
-
-    pub trait ManhattanDistance {
-        fn manhattan_distance(&self, rhs: &Self) -> i64;
-    }
 
-    impl ManhattanDistance for Vec2 {
-        fn manhattan_distance(&self, rhs: &Self) -> i64 {
-            (rhs.x - self.x).abs() + (rhs.y - self.y).abs()
+        //where a tile ends up after expand(expanded_size)
+        pub fn expanded_position(&self, position: Vec2, expanded_size: i64) -> Vec2 {
+            self.map
+                .stretcher(Vec2::new(0, 0), expanded_size)
+                .apply(position)
         }
     }
 }
 
-pub fn part1() -> i64 {
-    let mut universe = Universe::new(DAY11_INPUT);
+pub fn part1(input: &str) -> i64 {
+    let mut universe = Universe::new(input);
     universe.expand(2);
     calc_pair_distances(universe)
 }
@@ -79,14 +61,117 @@ fn calc_pair_distances(universe: Universe) -> i64 {
     let mut total = 0;
     for (s, g1) in universe.get_galaxies().enumerate() {
         for g2 in universe.get_galaxies().skip(s + 1) {
-            total += g1.manhattan_distance(g2);
+            total += g1.manhattan(*g2);
         }
     }
     total
 }
 
-pub fn part2() -> i64 {
-    let mut universe = Universe::new(DAY11_INPUT);
+pub fn part2(input: &str) -> i64 {
+    let mut universe = Universe::new(input);
     universe.expand(1_000_000);
     calc_pair_distances(universe)
 }
+
+pub fn repl(input: &str) {
+    let universe = Universe::new(input);
+    let position = |args: &[&str], i: usize| -> Result<Vec2<i64>, String> {
+        Ok(Vec2::new(arg(args, i, "x")?, arg(args, i + 1, "y")?))
+    };
+    repl::run(vec![
+        Command::new(
+            "where",
+            "<x> <y> <size>",
+            "where a tile ends up once empty space grows to size",
+            |args| {
+                let expanded_size = arg(args, 2, "size")?;
+                Ok(format!(
+                    "{:?}",
+                    universe.expanded_position(position(args, 0)?, expanded_size)
+                ))
+            },
+        ),
+        Command::new(
+            "empty",
+            "",
+            "list the empty rows and columns between the galaxies",
+            |_| {
+                let map = universe.map();
+                Ok(format!(
+                    "{} galaxies\nempty rows: {:?}\nempty columns: {:?}",
+                    map.len(),
+                    map.empty_rows(),
+                    map.empty_columns()
+                ))
+            },
+        ),
+        Command::new(
+            "distance",
+            "<x> <y> <x> <y> <size>",
+            "distance between two tiles once empty space grows to size",
+            |args| {
+                let expanded_size = arg(args, 4, "size")?;
+                let a = universe.expanded_position(position(args, 0)?, expanded_size);
+                let b = universe.expanded_position(position(args, 2)?, expanded_size);
+                Ok(a.manhattan(b).to_string())
+            },
+        ),
+    ]);
+}
+
+//walks across the map once along each axis, paying extra for empty rows and columns, and
+//keeps the running cost so each pair's path is the cost at one end less the other
+fn reference_distances(input: &str, expanded_size: i64) -> i64 {
+    let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let empty_row: Vec<bool> = grid
+        .iter()
+        .map(|row| row.iter().all(|&c| c == '.'))
+        .collect();
+    let empty_column: Vec<bool> = (0..grid[0].len())
+        .map(|x| grid.iter().all(|row| row[x] == '.'))
+        .collect();
+    //walked[i] is the cost of stepping from 0 to i
+    let walk = |empty: &[bool]| -> Vec<i64> {
+        let mut walked = vec![0];
+        for &e in empty {
+            walked.push(walked.last().unwrap() + if e { expanded_size } else { 1 });
+        }
+        walked
+    };
+    let (walked_x, walked_y) = (walk(&empty_column), walk(&empty_row));
+    let galaxies: Vec<(usize, usize)> = (0..grid.len())
+        .flat_map(|y| (0..grid[y].len()).map(move |x| (x, y)))
+        .filter(|&(x, y)| grid[y][x] == '#')
+        .collect();
+
+    let mut total = 0;
+    for (i, &(x1, y1)) in galaxies.iter().enumerate() {
+        for &(x2, y2) in &galaxies[i + 1..] {
+            total += (walked_x[x1] - walked_x[x2]).abs() + (walked_y[y1] - walked_y[y2]).abs();
+        }
+    }
+    total
+}
+
+pub fn reference1(input: &str) -> i64 {
+    reference_distances(input, 2)
+}
+
+pub fn reference2(input: &str) -> i64 {
+    reference_distances(input, 1_000_000)
+}
+
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 10 * scale;
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(1, 8)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.chance(1, 8)).collect();
+    let mut out = String::new();
+    for row_empty in &empty_rows {
+        for column_empty in &empty_columns {
+            let galaxy = !row_empty && !column_empty && rng.chance(1, 10);
+            out.push(if galaxy { '#' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -1,7 +1,13 @@
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::rng::Rng;
 use memoize::memoize;
+use std::collections::HashMap;
 
-const DAY12_INPUT: &str = include_str!(r"input\day12.txt");
-const _DAY12_INPUT_SIMPLE: &str = include_str!(r"input\day12_simple.txt");
+pub const INPUT: &str = include_str!(r"input\day12.txt");
+pub const EXAMPLES: &[&str] = &[include_str!(r"input\day12_simple.txt")];
+pub const PART1: Variants<usize> = &[("default", part1), ("dp-table", part1_table)];
+pub const PART2: Variants<usize> = &[("default", part2), ("dp-table", part2_table)];
 
 #[derive(Debug)]
 struct SpriteRecord {
@@ -116,18 +122,191 @@ fn count_ways(line: String, groups: Vec<usize>) -> usize {
     }
 }
 
-pub fn part1() -> usize {
-    let records = get_records(DAY12_INPUT);
+//table[i][j] is the number of ways groups[j..] fit into record[i..]
+fn count_ways_table(record: &str, groups: &[usize]) -> usize {
+    let springs: Vec<char> = record.chars().collect();
+    let len = springs.len();
+    //one extra row so a group that ends the record can skip its separator too
+    let mut table = vec![vec![0; groups.len() + 1]; len + 2];
+    table[len][groups.len()] = 1;
+    table[len + 1][groups.len()] = 1;
+    for i in (0..len).rev() {
+        for j in (0..=groups.len()).rev() {
+            let mut ways = 0;
+            if springs[i] != '#' {
+                ways += table[i + 1][j];
+            }
+            if springs[i] != '.' && j < groups.len() {
+                let end = i + groups[j];
+                if end <= len
+                    && springs[i..end].iter().all(|&c| c != '.')
+                    && springs.get(end) != Some(&'#')
+                {
+                    ways += table[end + 1][j + 1];
+                }
+            }
+            table[i][j] = ways;
+        }
+    }
+    table[0][0]
+}
+
+pub fn part1(input: &str) -> usize {
+    let records = get_records(input);
     records
         .iter()
         .map(|r| count_ways(r.record.to_owned(), r.groups.to_owned()))
         .sum()
 }
 
-pub fn part2() -> usize {
-    let records = get_folded_records(DAY12_INPUT);
+pub fn part2(input: &str) -> usize {
+    let records = get_folded_records(input);
     records
         .iter()
         .map(|r| count_ways(r.record.to_owned(), r.groups.to_owned()))
         .sum()
 }
+
+pub fn part1_table(input: &str) -> usize {
+    get_records(input)
+        .iter()
+        .map(|r| count_ways_table(&r.record, &r.groups))
+        .sum()
+}
+
+pub fn part2_table(input: &str) -> usize {
+    get_folded_records(input)
+        .iter()
+        .map(|r| count_ways_table(&r.record, &r.groups))
+        .sum()
+}
+
+pub fn repl(input: &str) {
+    let lines: Vec<&str> = input.lines().collect();
+    let ways = |line: &str| {
+        let record = SpriteRecord::new(line);
+        let folded = SpriteRecord::new_folded_record(line);
+        format!(
+            "{}\nfolded: {}",
+            count_ways(record.record, record.groups),
+            count_ways(folded.record, folded.groups)
+        )
+    };
+    repl::run(vec![
+        Command::new(
+            "record",
+            "<n>",
+            "count the arrangements of record n, unfolded and folded",
+            |args| {
+                let n: usize = arg(args, 0, "record number")?;
+                let line = lines
+                    .get(n.wrapping_sub(1))
+                    .ok_or_else(|| format!("no record {n}"))?;
+                Ok(format!("{line}\n{}", ways(line)))
+            },
+        ),
+        Command::new(
+            "ways",
+            "<record> <groups>",
+            "count the arrangements of any record",
+            |args| Ok(ways(&args.join(" "))),
+        ),
+    ]);
+}
+
+fn reference_groups(springs: &[char]) -> Vec<usize> {
+    springs
+        .split(|&c| c == '.')
+        .filter(|g| !g.is_empty())
+        .map(|g| g.len())
+        .collect()
+}
+
+//the most unknowns worth trying every way to fill in; 2^16 fills is still quick
+const MAX_BRUTE_UNKNOWNS: usize = 16;
+
+//tries all 2^n ways to fill in the unknowns, handing records with too many of them to
+//reference_ways
+pub fn reference1(input: &str) -> usize {
+    get_records(input)
+        .iter()
+        .map(|r| {
+            let springs: Vec<char> = r.record.chars().collect();
+            let unknowns: Vec<usize> = (0..springs.len()).filter(|&i| springs[i] == '?').collect();
+            if unknowns.len() > MAX_BRUTE_UNKNOWNS {
+                return reference_ways(&r.record, &r.groups);
+            }
+            (0..1u32 << unknowns.len())
+                .filter(|mask| {
+                    let mut filled = springs.clone();
+                    for (bit, &i) in unknowns.iter().enumerate() {
+                        filled[i] = if mask & (1 << bit) != 0 { '#' } else { '.' };
+                    }
+                    reference_groups(&filled) == r.groups
+                })
+                .count()
+        })
+        .sum()
+}
+
+//2^n is out of reach once folded, so this follows every arrangement at once instead,
+//merging the ones that agree on (groups finished, length of the current run). states that
+//die out later can still count past the answer, so they're counted in u128
+fn reference_ways(record: &str, groups: &[usize]) -> usize {
+    let mut states: HashMap<(usize, usize), u128> = HashMap::from([((0, 0), 1)]);
+    for c in record.chars().chain(['.']) {
+        let mut next = HashMap::new();
+        for ((done, run), ways) in states {
+            if c != '.' && done < groups.len() && run < groups[done] {
+                *next.entry((done, run + 1)).or_insert(0) += ways;
+            }
+            if c != '#' {
+                if run == 0 {
+                    *next.entry((done, 0)).or_insert(0) += ways;
+                } else if run == groups[done] {
+                    *next.entry((done + 1, 0)).or_insert(0) += ways;
+                }
+            }
+        }
+        states = next;
+    }
+    let ways = states.get(&(groups.len(), 0)).copied().unwrap_or(0);
+    ways.try_into().expect("too many arrangements for usize")
+}
+
+pub fn reference2(input: &str) -> usize {
+    get_folded_records(input)
+        .iter()
+        .map(|r| reference_ways(&r.record, &r.groups))
+        .sum()
+}
+
+//scale folds each record that many times, the same way part 2 does
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..10 {
+        let mut springs: Vec<char> = (0..rng.range(1..=15))
+            .map(|_| if rng.chance(1, 2) { '#' } else { '.' })
+            .collect();
+        //the group list can't be empty
+        if !springs.contains(&'#') {
+            let i = rng.range(0..springs.len());
+            springs[i] = '#';
+        }
+        let groups: Vec<String> = springs
+            .split(|&c| c == '.')
+            .filter(|g| !g.is_empty())
+            .map(|g| g.len().to_string())
+            .collect();
+        let record: String = springs
+            .iter()
+            .map(|&c| if rng.chance(1, 2) { '?' } else { c })
+            .collect();
+
+        out.push_str(&vec![record; scale].join("?"));
+        out.push(' ');
+        out.push_str(&vec![groups.join(","); scale].join(","));
+        out.push('\n');
+    }
+    out
+}
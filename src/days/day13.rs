@@ -1,103 +1,223 @@
+use crate::days::repl::{self, arg, Command};
+use crate::days::Variants;
+use crate::helper::bit_grid::BitGrid;
+use crate::helper::grid::Grid;
+use crate::helper::rng::Rng;
 use crate::helper::vec2::Vec2;
-use lazy_static::lazy_static;
-use regex::Regex;
 
-const DAY13_INPUT: &str = include_str!(r"input\day13.txt");
-
-lazy_static! {
-    static ref DOUBLE_NEWLINE: Regex = Regex::new(r"(\r\n){2}|\n{2}").unwrap();
-}
+pub const INPUT: &str = include_str!(r"input\day13.txt");
+pub const EXAMPLES: &[&str] = &[];
+pub const PART1: Variants<usize> = &[("default", part1)];
+pub const PART2: Variants<usize> = &[("default", part2)];
 
 #[derive(Debug)]
 struct Map {
-    tiles: Vec<Vec<char>>,
-    size: Vec2<usize>,
+    tiles: Grid<char>,
 }
 
 impl Map {
     fn new(input: &str) -> Self {
-        let size = Vec2::new(
-            input
-                .lines()
-                .next()
-                .expect("could not find first line")
-                .chars()
-                .count(),
-            input.lines().count(),
-        );
-        let tiles = input.lines().map(|l| l.chars().collect()).collect();
-        Map { tiles, size }
+        let tiles = Grid::parse(input, |_, c| match c {
+            '#' | '.' => Ok(c),
+            _ => Err(format!("unknown tile: {c}")),
+        });
+        Map {
+            tiles: tiles.unwrap_or_else(|e| panic!("could not parse map: {e}")),
+        }
     }
 
-    fn get_symmetry(&self) -> Vec2<usize> {
-        //columns
-        for x in 0..self.size.x {
-            if self.is_symmetric_over(x, false) {
-                return Vec2::new(x, 0);
-            }
+    //the mirror where exactly this many tiles fail to match their reflection
+    fn get_symmetry(&self, smudges: usize) -> Vec2<usize> {
+        let rocks = BitGrid::from_grid(&self.tiles, |&c| c == '#');
+        if let Some(x) = mirrored_rows(&rocks.transposed(), smudges) {
+            return Vec2::new(x, 0);
         }
-
-        for y in 0..self.size.y {
-            if self.is_symmetric_over(y, true) {
-                return Vec2::new(0, y);
-            }
+        if let Some(y) = mirrored_rows(&rocks, smudges) {
+            return Vec2::new(0, y);
         }
-        panic!("could not find symmetry for map: {:?}", self)
+        panic!(
+            "could not find symmetry with {smudges} smudges for map: {:?}",
+            self
+        )
     }
+}
 
-    fn is_symmetric_over(&self, start: usize, is_row: bool) -> bool {
-        if is_row {
-            if start == 0 || start + 1 == self.size.y {
-                return false;
-            }
+//rows above the first line the grid mirrors over top to bottom, with smudges tiles that
+//don't match; columns work the same on the transposed grid
+fn mirrored_rows(rocks: &BitGrid, smudges: usize) -> Option<usize> {
+    let height = rocks.height();
+    (1..height).find(|&line| {
+        let mismatches: usize = (0..line.min(height - line))
+            .map(|d| rocks.row_difference(line + d, line - 1 - d))
+            .sum();
+        mismatches == smudges
+    })
+}
 
-            for y in start + 1..self.size.y {
-                let distance = y - start - 1;
-                if start < distance {
-                    break;
-                }
-                let opposite = start - distance;
-                for x in 0..self.size.x {
-                    if self.tiles[x][y] != self.tiles[x][opposite] {
-                        return false;
-                    }
-                }
+fn get_maps(input: &str) -> Vec<Map> {
+    input.split("\n\n").map(|i| Map::new(i)).collect()
+}
+
+fn summarize(input: &str, smudges: usize) -> usize {
+    let out: Vec2<_> = get_maps(input)
+        .into_iter()
+        .map(|m| m.get_symmetry(smudges))
+        .sum();
+    out.x + 100 * out.y
+}
+
+pub fn part1(input: &str) -> usize {
+    summarize(input, 0)
+}
+
+pub fn part2(input: &str) -> usize {
+    summarize(input, 1)
+}
+
+pub fn repl(input: &str) {
+    let maps = get_maps(input);
+    repl::run(vec![Command::new(
+        "map",
+        "<n>",
+        "show map n and where it mirrors, without and with a smudge",
+        |args| {
+            let n: usize = arg(args, 0, "map number")?;
+            let map = maps
+                .get(n.wrapping_sub(1))
+                .ok_or_else(|| format!("no map {n}"))?;
+            let tiles: Vec<String> = map.tiles.rows().map(|r| r.iter().collect()).collect();
+            Ok(format!(
+                "{}\nclean {:?}\nsmudged {:?}",
+                tiles.join("\n"),
+                map.get_symmetry(0),
+                map.get_symmetry(1)
+            ))
+        },
+    )]);
+}
+
+//every possible mirror as (columns left of it, rows above it, tiles that don't match)
+fn reference_mirrors(tiles: &[Vec<char>]) -> Vec<(usize, usize, usize)> {
+    let height = tiles.len();
+    let width = tiles[0].len();
+    let columns = (1..width).map(|line| {
+        let mismatches = tiles
+            .iter()
+            .flat_map(|row| (0..line.min(width - line)).map(move |d| (row, d)))
+            .filter(|(row, d)| row[line + d] != row[line - 1 - d])
+            .count();
+        (line, 0, mismatches)
+    });
+    let rows = (1..height).map(|line| {
+        let mismatches = (0..line.min(height - line))
+            .flat_map(|d| (0..width).map(move |x| (d, x)))
+            .filter(|&(d, x)| tiles[line + d][x] != tiles[line - 1 - d][x])
+            .count();
+        (0, line, mismatches)
+    });
+    columns.chain(rows).collect()
+}
+
+fn reference_summary(input: &str, smudges: usize) -> usize {
+    input
+        .split("\n\n")
+        .map(|map| {
+            let tiles: Vec<Vec<char>> = map.lines().map(|l| l.chars().collect()).collect();
+            let (columns, rows, _) = reference_mirrors(&tiles)
+                .into_iter()
+                .find(|m| m.2 == smudges)
+                .unwrap();
+            columns + 100 * rows
+        })
+        .sum()
+}
+
+pub fn reference1(input: &str) -> usize {
+    reference_summary(input, 0)
+}
+
+pub fn reference2(input: &str) -> usize {
+    reference_summary(input, 1)
+}
+
+//mirrors every row over a column line, then the rows over a row line, then smudges one
+//tile that only the row mirror covers. half the maps get transposed so either can be first
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut maps = Vec::new();
+    while maps.len() < 10 * scale {
+        let width = rng.range(5..=17);
+        let height = rng.range(5..=17);
+        let column = rng.range(1..width);
+        let row = rng.range(1..height);
+        let column_span = column.min(width - column);
+        let row_span = row.min(height - row);
+        //a centred column mirror covers every tile, leaving nowhere to smudge
+        if column_span * 2 == width {
+            continue;
+        }
+
+        let mut tiles: Vec<Vec<char>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.chance(1, 2) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        for r in tiles.iter_mut() {
+            for d in 0..column_span {
+                r[column + d] = r[column - 1 - d];
             }
-            return true;
+        }
+        for d in 0..row_span {
+            tiles[row + d] = tiles[row - 1 - d].clone();
+        }
+        let x = if column < width - column {
+            rng.range(column * 2..width)
         } else {
-            if start == 0 || start + 1 == self.size.x {
-                return false;
-            }
+            rng.range(0..column - column_span)
+        };
+        let y = rng.range(row - row_span..row + row_span);
+        tiles[y][x] = if tiles[y][x] == '#' { '.' } else { '#' };
 
-            for x in start + 1..self.size.x {
-                let distance = x - start - 1;
-                if start < distance {
-                    break;
-                }
-                let opposite = start - distance;
-                for y in 0..self.size.y {
-                    if self.tiles[x][y] != self.tiles[opposite][y] {
-                        return false;
-                    }
-                }
-            }
-            return true;
+        if rng.chance(1, 2) {
+            tiles = transposed(&tiles);
+        }
+
+        //random tiles can line up into more mirrors; only keep maps with one of each
+        if count_mirrors(&tiles, 0) == 1 && count_mirrors(&tiles, 1) == 1 {
+            maps.push(
+                tiles
+                    .iter()
+                    .map(|r| r.iter().collect::<String>() + "\n")
+                    .collect::<String>(),
+            );
         }
     }
+    maps.join("\n")
 }
 
-fn get_maps(input: &str) -> Vec<Map> {
-    DOUBLE_NEWLINE.split(input).map(|i| Map::new(i)).collect()
+fn transposed(tiles: &[Vec<char>]) -> Vec<Vec<char>> {
+    (0..tiles[0].len())
+        .map(|x| tiles.iter().map(|r| r[x]).collect())
+        .collect()
 }
 
-pub fn part1() -> usize {
-    let out: Vec2<_> = get_maps(DAY13_INPUT)
-        .into_iter()
-        .map(|m| m.get_symmetry())
-        .sum();
-    return out.x + 100 * out.y;
+//row and column lines with exactly this many tiles that don't match across them
+fn count_mirrors(tiles: &[Vec<char>], smudges: usize) -> usize {
+    count_row_mirrors(tiles, smudges) + count_row_mirrors(&transposed(tiles), smudges)
 }
 
-pub fn part2() -> i32 {
-    0
+fn count_row_mirrors(tiles: &[Vec<char>], smudges: usize) -> usize {
+    let height = tiles.len();
+    (1..height)
+        .filter(|&line| {
+            let mismatches: usize = (0..line.min(height - line))
+                .map(|d| {
+                    let (a, b) = (&tiles[line + d], &tiles[line - 1 - d]);
+                    a.iter().zip(b).filter(|(a, b)| a != b).count()
+                })
+                .sum();
+            mismatches == smudges
+        })
+        .count()
 }
@@ -0,0 +1,82 @@
+use std::{
+    io::{stdin, stdout, BufRead, Write},
+    str::FromStr,
+};
+
+use super::catch_answer;
+
+type Action<'a> = Box<dyn Fn(&[&str]) -> Result<String, String> + 'a>;
+
+//args is how the arguments look in help, like "<x> <y>", or empty when there are none
+pub struct Command<'a> {
+    name: &'static str,
+    args: &'static str,
+    description: &'static str,
+    action: Action<'a>,
+}
+
+impl<'a> Command<'a> {
+    pub fn new<F>(
+        name: &'static str,
+        args: &'static str,
+        description: &'static str,
+        action: F,
+    ) -> Self
+    where
+        F: Fn(&[&str]) -> Result<String, String> + 'a,
+    {
+        Command {
+            name,
+            args,
+            description,
+            action: Box::new(action),
+        }
+    }
+
+    fn usage(&self) -> String {
+        if self.args.is_empty() {
+            format!("{}: {}", self.name, self.description)
+        } else {
+            format!("{} {}: {}", self.name, self.args, self.description)
+        }
+    }
+}
+
+//reads commands from stdin until quit or end of input
+pub fn run(commands: Vec<Command>) {
+    println!("type help for commands, quit to leave");
+    let mut lines = stdin().lock().lines();
+    loop {
+        print!("> ");
+        stdout().flush().expect("could not flush stdout");
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => {}
+            ["quit" | "exit"] => break,
+            ["help"] => {
+                for c in &commands {
+                    println!("{}", c.usage());
+                }
+            }
+            [name, args @ ..] => match commands.iter().find(|c| c.name == *name) {
+                //a panicking model shouldn't end the session
+                Some(c) => match catch_answer(|| (c.action)(args)) {
+                    Ok(Ok(out)) => println!("{out}"),
+                    Ok(Err(message)) => println!("error: {message}"),
+                    Err(message) => println!("panicked: {message}"),
+                },
+                None => println!("unknown command: {name}, try help"),
+            },
+        }
+    }
+}
+
+pub fn arg<T: FromStr>(args: &[&str], i: usize, name: &str) -> Result<T, String> {
+    let value = args.get(i).ok_or_else(|| format!("missing {name}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("could not parse {name}: {value}"))
+}
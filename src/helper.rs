@@ -1,2 +1,17 @@
+pub mod bit_grid;
+pub mod direction;
+pub mod flood;
+pub mod grid;
 pub mod grouper;
+pub mod input;
+pub mod num;
+pub mod point_set;
+pub mod poly;
+pub mod polygon;
+pub mod rational;
+pub mod rect;
+pub mod render;
+pub mod rng;
+pub mod search;
 pub mod vec2;
+pub mod vec3;
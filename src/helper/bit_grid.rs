@@ -0,0 +1,172 @@
+use std::fmt::Debug;
+
+use super::{grid::Grid, vec2::Vec2};
+
+const WORD: usize = u64::BITS as usize;
+
+//a grid of bools packed 64 to a word, each row starting on a fresh word so rows can be
+//compared and counted a word at a time. bit x % 64 of word x / 64 is column x
+#[derive(Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD);
+        BitGrid {
+            words: vec![0; words_per_row * height],
+            width,
+            height,
+            words_per_row,
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut f: impl FnMut(&T) -> bool) -> Self {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        for (pos, value) in grid.iter() {
+            if f(value) {
+                bits.set(pos, true);
+            }
+        }
+        bits
+    }
+
+    #[allow(dead_code)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn locate<I: TryInto<usize>>(&self, pos: Vec2<I>) -> Option<(usize, u64)> {
+        let x: usize = pos.x.try_into().ok()?;
+        let y: usize = pos.y.try_into().ok()?;
+        (x < self.width && y < self.height)
+            .then(|| (y * self.words_per_row + x / WORD, 1 << (x % WORD)))
+    }
+
+    //false outside the grid
+    pub fn get<I: TryInto<usize>>(&self, pos: Vec2<I>) -> bool {
+        self.locate(pos)
+            .is_some_and(|(i, mask)| self.words[i] & mask != 0)
+    }
+
+    //returns what was there before; setting outside the grid panics
+    pub fn set<I: TryInto<usize> + Copy + Debug>(&mut self, pos: Vec2<I>, value: bool) -> bool {
+        let (i, mask) = self
+            .locate(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos));
+        let was = self.words[i] & mask != 0;
+        if value {
+            self.words[i] |= mask;
+        } else {
+            self.words[i] &= !mask;
+        }
+        was
+    }
+
+    //the packed words of row y; bits past the width are always clear
+    pub fn row(&self, y: usize) -> &[u64] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn row_count_ones(&self, y: usize) -> usize {
+        self.row(y).iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    //how many columns rows a and b disagree on
+    pub fn row_difference(&self, a: usize, b: usize) -> usize {
+        self.row(a)
+            .iter()
+            .zip(self.row(b))
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    //columns become rows, so column checks can use the row methods
+    pub fn transposed(&self) -> Self {
+        let mut out = BitGrid::new(self.height, self.width);
+        for pos in self.iter_ones() {
+            out.set(Vec2::new(pos.y, pos.x), true);
+        }
+        out
+    }
+
+    fn combine(&mut self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "bit grids are different sizes"
+        );
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a | b);
+    }
+
+    #[allow(dead_code)]
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a & b);
+    }
+
+    #[allow(dead_code)]
+    pub fn subtract(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a & !b);
+    }
+
+    //flips every bit inside the grid
+    #[allow(dead_code)]
+    pub fn invert(&mut self) {
+        let tail = self.width % WORD;
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word = !*word;
+            if tail != 0 && i % self.words_per_row == self.words_per_row - 1 {
+                *word &= (1 << tail) - 1;
+            }
+        }
+    }
+
+    //set positions in reading order, skipping clear words entirely
+    pub fn iter_ones(&self) -> impl Iterator<Item = Vec2<i32>> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let y = (i / self.words_per_row) as i32;
+            let base = (i % self.words_per_row * WORD) as i32;
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as i32;
+                    rest &= rest - 1;
+                    Vec2::new(base + bit, y)
+                })
+            })
+        })
+    }
+}
+
+impl Debug for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "BitGrid {}x{}", self.width, self.height)?;
+        for y in 0..self.height {
+            let row: String = (0..self.width)
+                .map(|x| if self.get(Vec2::new(x, y)) { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
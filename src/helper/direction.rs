@@ -0,0 +1,196 @@
+use std::str::FromStr;
+
+use super::vec2::{Signed, Vec2};
+
+//the four compass directions, listed clockwise from north
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    fn from_index(i: usize) -> Self {
+        Self::ALL[i % Self::ALL.len()]
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 3)
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+}
+
+impl<T: Signed> From<Dir4> for Vec2<T> {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::North => Vec2::NORTH,
+            Dir4::East => Vec2::EAST,
+            Dir4::South => Vec2::SOUTH,
+            Dir4::West => Vec2::WEST,
+        }
+    }
+}
+
+impl<T: Signed + PartialEq> TryFrom<Vec2<T>> for Dir4 {
+    type Error = String;
+
+    fn try_from(v: Vec2<T>) -> Result<Self, Self::Error> {
+        Dir4::ALL
+            .into_iter()
+            .find(|&d| Vec2::from(d) == v)
+            .ok_or_else(|| "not a single step north, east, south or west".to_owned())
+    }
+}
+
+//N E S W, U R D L and arrows, in either case
+impl TryFrom<char> for Dir4 {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'N' | 'U' | '^' => Ok(Dir4::North),
+            'E' | 'R' | '>' => Ok(Dir4::East),
+            'S' | 'D' | 'V' => Ok(Dir4::South),
+            'W' | 'L' | '<' => Ok(Dir4::West),
+            _ => Err(format!("unknown direction: {c}")),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c),
+            _ => Err(format!("unknown direction: {s}")),
+        }
+    }
+}
+
+//the four compass directions and the four between them, listed clockwise from north
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    fn from_index(i: usize) -> Self {
+        Self::ALL[i % Self::ALL.len()]
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    //turns are a right angle, like Dir4; the half turns go an eighth of the way round
+    #[allow(dead_code)]
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 6)
+    }
+
+    #[allow(dead_code)]
+    pub fn half_turn_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    #[allow(dead_code)]
+    pub fn half_turn_left(self) -> Self {
+        Self::from_index(self.index() + 7)
+    }
+
+    #[allow(dead_code)]
+    pub fn reverse(self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::from_index(dir.index() * 2)
+    }
+}
+
+impl TryFrom<Dir8> for Dir4 {
+    type Error = String;
+
+    fn try_from(dir: Dir8) -> Result<Self, Self::Error> {
+        match dir.index() {
+            i if i % 2 == 0 => Ok(Dir4::from_index(i / 2)),
+            _ => Err(format!("{dir:?} is not a compass direction")),
+        }
+    }
+}
+
+impl<T: Signed> From<Dir8> for Vec2<T> {
+    fn from(dir: Dir8) -> Self {
+        Vec2::ALL_EIGHT[dir.index()]
+    }
+}
+
+impl<T: Signed + PartialEq> TryFrom<Vec2<T>> for Dir8 {
+    type Error = String;
+
+    fn try_from(v: Vec2<T>) -> Result<Self, Self::Error> {
+        Dir8::ALL
+            .into_iter()
+            .find(|&d| Vec2::from(d) == v)
+            .ok_or_else(|| "not a single step in any direction".to_owned())
+    }
+}
+
+//N, NE, E and so on, or a single U D L R or arrow for the compass directions
+impl FromStr for Dir8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "NE" => Ok(Dir8::NorthEast),
+            "SE" => Ok(Dir8::SouthEast),
+            "SW" => Ok(Dir8::SouthWest),
+            "NW" => Ok(Dir8::NorthWest),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
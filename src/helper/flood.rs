@@ -0,0 +1,48 @@
+use std::collections::VecDeque;
+
+use super::{
+    bit_grid::BitGrid,
+    grid::{neighbors4, Edges},
+    vec2::Vec2,
+};
+
+//what the positions in a fill stand for on a grid of a given size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lattice {
+    //the cells themselves, 0..size
+    Cells,
+    //the corners between cells, 0..=size, so a fill runs along the grid lines and can
+    //squeeze between two cells that touch. corner (x, y) is the top left of cell (x, y)
+    Corners,
+}
+
+//spreads from every start along the edges passable(from, to) allows and returns which
+//positions were reached; starts outside the lattice are ignored
+pub fn flood_fill(
+    starts: impl IntoIterator<Item = Vec2<i32>>,
+    size: Vec2<i32>,
+    lattice: Lattice,
+    mut passable: impl FnMut(Vec2<i32>, Vec2<i32>) -> bool,
+) -> BitGrid {
+    let bounds = match lattice {
+        Lattice::Cells => size,
+        Lattice::Corners => size + Vec2::new(1, 1),
+    };
+    let cells = bounds.cast::<usize>();
+    let mut filled = BitGrid::new(cells.x, cells.y);
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if Edges::Bounded.resolve(start, bounds).is_some() && !filled.set(start, true) {
+            queue.push_back(start);
+        }
+    }
+    while let Some(current) = queue.pop_front() {
+        for next in neighbors4(current, bounds, Edges::Bounded) {
+            if !filled.get(next) && passable(current, next) {
+                filled.set(next, true);
+                queue.push_back(next);
+            }
+        }
+    }
+    filled
+}
@@ -0,0 +1,345 @@
+use std::{collections::HashMap, fmt::Display, ops::Index};
+
+use super::{rect::Rect, vec2::Vec2};
+
+//what happens to positions that step off a grid of a given size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    //they're skipped
+    Bounded,
+    //the grid repeats forever, so they wrap round to the other side
+    #[allow(dead_code)]
+    Wrapping,
+}
+
+impl Edges {
+    pub fn resolve(self, pos: Vec2<i32>, size: Vec2<i32>) -> Option<Vec2<i32>> {
+        match self {
+            Edges::Bounded => Rect::from_size(size).contains(pos).then_some(pos),
+            Edges::Wrapping => Some(pos.rem_euclid(size)),
+        }
+    }
+}
+
+pub fn neighbors4(
+    pos: Vec2<i32>,
+    size: Vec2<i32>,
+    edges: Edges,
+) -> impl Iterator<Item = Vec2<i32>> {
+    Vec2::<i32>::ALL_CARDINAL
+        .iter()
+        .filter_map(move |&d| edges.resolve(pos + d, size))
+}
+
+pub fn neighbors8(
+    pos: Vec2<i32>,
+    size: Vec2<i32>,
+    edges: Edges,
+) -> impl Iterator<Item = Vec2<i32>> {
+    Vec2::<i32>::ALL_EIGHT
+        .iter()
+        .filter_map(move |&d| edges.resolve(pos + d, size))
+}
+
+//the ring just outside the rectangle from min to max inclusive, corners included.
+//when wrapping on a grid barely bigger than the rectangle the same position can come up twice
+pub fn around(
+    min: Vec2<i32>,
+    max: Vec2<i32>,
+    size: Vec2<i32>,
+    edges: Edges,
+) -> impl Iterator<Item = Vec2<i32>> {
+    let above_and_below = (min.x - 1..=max.x + 1)
+        .flat_map(move |x| [Vec2::new(x, min.y - 1), Vec2::new(x, max.y + 1)]);
+    let sides =
+        (min.y..=max.y).flat_map(move |y| [Vec2::new(min.x - 1, y), Vec2::new(max.x + 1, y)]);
+    above_and_below
+        .chain(sides)
+        .filter_map(move |p| edges.resolve(p, size))
+}
+
+//positions of the characters asked for while parsing, in reading order
+pub type Marks = HashMap<char, Vec<Vec2<i32>>>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError<E> {
+    //line is counted from 1, like an editor would
+    Ragged {
+        line: usize,
+        length: usize,
+        expected: usize,
+    },
+    Cell {
+        pos: Vec2<i32>,
+        error: E,
+    },
+}
+
+impl<E: Display> Display for ParseError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Ragged {
+                line,
+                length,
+                expected,
+            } => write!(f, "line {line} has length {length}, expected {expected}"),
+            ParseError::Cell { pos, error } => write!(f, "at {},{}: {error}", pos.x, pos.y),
+        }
+    }
+}
+
+//row-major storage; positions outside the grid, including negative ones, just give None
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    //f is called with every position in reading order
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Vec2<i32>) -> T) -> Self {
+        let positions = Rect::from_size(Vec2::new(width, height).cast()).points();
+        Grid {
+            cells: positions.map(&mut f).collect(),
+            width,
+            height,
+        }
+    }
+
+    //one cell per character, every line must be as long as the first
+    pub fn parse<E>(
+        input: &str,
+        f: impl FnMut(Vec2<i32>, char) -> Result<T, E>,
+    ) -> Result<Self, ParseError<E>> {
+        Self::parse_marked(input, "", f).map(|(grid, _)| grid)
+    }
+
+    //like parse, but also notes where each of the characters in marks appears
+    pub fn parse_marked<E>(
+        input: &str,
+        marks: &str,
+        mut f: impl FnMut(Vec2<i32>, char) -> Result<T, E>,
+    ) -> Result<(Self, Marks), ParseError<E>> {
+        let mut cells = Vec::new();
+        let mut found = Marks::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let length = line.chars().count();
+            let expected = *width.get_or_insert(length);
+            if length != expected {
+                return Err(ParseError::Ragged {
+                    line: y + 1,
+                    length,
+                    expected,
+                });
+            }
+            for (x, c) in line.chars().enumerate() {
+                let pos = Vec2::new(x, y).cast();
+                if marks.contains(c) {
+                    found.entry(c).or_default().push(pos);
+                }
+                cells.push(f(pos, c).map_err(|error| ParseError::Cell { pos, error })?);
+            }
+            height += 1;
+        }
+        let grid = Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        };
+        Ok((grid, found))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> Vec2<i32> {
+        Vec2::new(self.width, self.height).cast()
+    }
+
+    pub fn rect(&self) -> Rect<i32> {
+        Rect::from_size(self.size())
+    }
+
+    fn index<I: TryInto<usize>>(&self, pos: Vec2<I>) -> Option<usize> {
+        let x = pos.x.try_into().ok()?;
+        let y = pos.y.try_into().ok()?;
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    pub fn get<I: TryInto<usize>>(&self, pos: Vec2<I>) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    #[allow(dead_code)]
+    pub fn get_mut<I: TryInto<usize>>(&mut self, pos: Vec2<I>) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> Column<'_, T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        Column { grid: self, x }
+    }
+
+    #[allow(dead_code)]
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    //every position in reading order, as i32 to match the direction constants on Vec2
+    pub fn positions(&self) -> impl Iterator<Item = Vec2<i32>> {
+        self.rect().points()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<i32>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    #[allow(dead_code)]
+    pub fn neighbors4(&self, pos: Vec2<i32>, edges: Edges) -> impl Iterator<Item = Vec2<i32>> {
+        neighbors4(pos, self.size(), edges)
+    }
+
+    #[allow(dead_code)]
+    pub fn neighbors8(&self, pos: Vec2<i32>, edges: Edges) -> impl Iterator<Item = Vec2<i32>> {
+        neighbors8(pos, self.size(), edges)
+    }
+
+    pub fn around(
+        &self,
+        min: Vec2<i32>,
+        max: Vec2<i32>,
+        edges: Edges,
+    ) -> impl Iterator<Item = Vec2<i32>> {
+        around(min, max, self.size(), edges)
+    }
+}
+
+//the transforms copy every cell once; rows are contiguous so that's as cheap as a view
+//that would have to remap every lookup
+impl<T: Clone> Grid<T> {
+    //swaps x and y, so rows become columns
+    #[allow(dead_code)]
+    pub fn transposed(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vec2::new(p.y, p.x)].clone()
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn rotated_clockwise(&self) -> Self {
+        let last = self.height as i32 - 1;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vec2::new(p.y, last - p.x)].clone()
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn rotated_counterclockwise(&self) -> Self {
+        let last = self.width as i32 - 1;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vec2::new(last - p.y, p.x)].clone()
+        })
+    }
+
+    //mirrors left to right
+    #[allow(dead_code)]
+    pub fn flipped_horizontally(&self) -> Self {
+        let last = self.width as i32 - 1;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Vec2::new(last - p.x, p.y)].clone()
+        })
+    }
+
+    //mirrors top to bottom
+    #[allow(dead_code)]
+    pub fn flipped_vertically(&self) -> Self {
+        let last = self.height as i32 - 1;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Vec2::new(p.x, last - p.y)].clone()
+        })
+    }
+}
+
+//a column read in place; compares by its values like a row slice does
+#[derive(Debug)]
+pub struct Column<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+}
+
+impl<T> Clone for Column<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Column<'_, T> {}
+
+impl<'a, T> Column<'a, T> {
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.grid.height
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.grid.height == 0
+    }
+
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        (*self).into_iter()
+    }
+}
+
+impl<T> Index<usize> for Column<'_, T> {
+    type Output = T;
+
+    fn index(&self, y: usize) -> &Self::Output {
+        assert!(y < self.grid.height, "row {y} is outside the grid");
+        &self.grid.cells[self.x + y * self.grid.width]
+    }
+}
+
+impl<T: PartialEq> PartialEq for Column<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Column<'_, T> {}
+
+impl<'a, T> IntoIterator for Column<'a, T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::StepBy<std::iter::Skip<std::slice::Iter<'a, T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.grid.cells.iter().skip(self.x).step_by(self.grid.width)
+    }
+}
+
+impl<T> Index<Vec2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2<i32>) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{},{} is outside the grid", pos.x, pos.y))
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+//what normalize had to fix, so surprising inputs can be reported
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub byte_order_mark: bool,
+    pub crlf_endings: usize,
+    pub cr_endings: usize,
+    pub trailing_whitespace: usize,
+    pub trailing_blank_lines: usize,
+}
+
+impl Display for Changes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut notes = Vec::new();
+        if self.byte_order_mark {
+            notes.push("removed byte order mark".to_owned());
+        }
+        for (count, what) in [
+            (self.crlf_endings, "converted \\r\\n line endings"),
+            (self.cr_endings, "converted \\r line endings"),
+            (
+                self.trailing_whitespace,
+                "trimmed lines with trailing whitespace",
+            ),
+            (self.trailing_blank_lines, "removed trailing blank lines"),
+        ] {
+            if count > 0 {
+                notes.push(format!("{what}: {count}"));
+            }
+        }
+        if notes.is_empty() {
+            notes.push("no changes".to_owned());
+        }
+        write!(f, "{}", notes.join(", "))
+    }
+}
+
+//strips a BOM, turns \r\n and lone \r into \n, trims the end of every line and drops
+//trailing blank lines; the result always ends in exactly one \n unless it's empty
+pub fn normalize(input: &str) -> (String, Changes) {
+    let mut changes = Changes::default();
+
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.byte_order_mark = true;
+            rest
+        }
+        None => input,
+    };
+
+    changes.crlf_endings = input.matches("\r\n").count();
+    let input = input.replace("\r\n", "\n");
+    changes.cr_endings = input.matches('\r').count();
+    let input = input.replace('\r', "\n");
+
+    let mut lines: Vec<&str> = input
+        .split('\n')
+        .map(|l| {
+            let trimmed = l.trim_end();
+            if trimmed.len() != l.len() {
+                changes.trailing_whitespace += 1;
+            }
+            trimmed
+        })
+        .collect();
+    //the last piece is what follows the final line ending, normally nothing
+    if lines.last() == Some(&"") {
+        lines.pop();
+    }
+    while lines.last() == Some(&"") {
+        lines.pop();
+        changes.trailing_blank_lines += 1;
+    }
+
+    let mut out = lines.join("\n");
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    (out, changes)
+}
@@ -0,0 +1,174 @@
+use std::ops::{Div, Mul, Rem};
+
+//the integer types gcd and lcm work on, signed or not
+pub trait Integer:
+    Copy + Ord + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    //the size without the sign; unsigned types are already there
+    fn magnitude(self) -> Self;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($magnitude:expr; $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn magnitude(self) -> Self {
+                    $magnitude(self)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(|n: Self| n.abs(); i8, i16, i32, i64, i128, isize);
+impl_integer!(|n: Self| n; u8, u16, u32, u64, u128, usize);
+
+//never negative; gcd(0, 0) is 0
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.magnitude(), b.magnitude());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+//divides before multiplying, so this only overflows if the answer does
+#[allow(dead_code)]
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("lcm overflowed")
+}
+
+//None if the answer doesn't fit. 0 if either is 0
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a.magnitude() / gcd(a, b)).checked_mul(b.magnitude())
+}
+
+//the lcm of everything, 1 for nothing at all
+pub fn checked_lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |total, v| checked_lcm(total, v))
+}
+
+//(g, x, y) with a * x + b * y = g, where g is the gcd
+#[allow(dead_code)]
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a.abs(), a.signum(), 0);
+    }
+    let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+    (g, y, x - a.div_euclid(b) * y)
+}
+
+//x with a * x = 1 mod m, in 0..m. None when a and m share a factor
+#[allow(dead_code)]
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a, m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+//base^exp mod m by squaring, in 0..m
+#[allow(dead_code)]
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut out = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            out = out * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    out as u64
+}
+
+//chinese remainder theorem: the x that is r mod m for every (r, m) pair, as (x, lcm of
+//the moduli) with x in 0..lcm. the moduli don't have to be coprime; None if the pairs
+//contradict each other or the lcm overflows. moduli must be positive
+#[allow(dead_code)]
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            assert!(m2 > 0, "modulus must be positive, got {m2}");
+            let (g, p, _) = extended_gcd(m1, m2);
+            let diff = r2 - r1;
+            if diff % g != 0 {
+                return None;
+            }
+            //m1 * p = g mod m2, so stepping r1 by m1 * k lands on r2 mod m2
+            let step = m2 / g;
+            let k = (diff / g)
+                .rem_euclid(step)
+                .checked_mul(p.rem_euclid(step))?
+                % step;
+            let m = m1.checked_mul(step)?;
+            Some(((r1 + m1 * k).rem_euclid(m), m))
+        })
+}
+
+//the largest x with x * x <= n. f64 gets close and the loops fix the last few bits
+pub fn isqrt(n: u128) -> u128 {
+    let mut x = (n as f64).sqrt() as u128;
+    while x.checked_mul(x).is_none_or(|sq| sq > n) {
+        x -= 1;
+    }
+    while (x + 1).checked_mul(x + 1).is_some_and(|sq| sq <= n) {
+        x += 1;
+    }
+    x
+}
+
+//every integer x with a*x^2 + b*x + c > 0, as an inclusive (min, max), or None if there
+//are none. a has to be negative so the answer is a single interval. Err when the
+//discriminant doesn't fit in a u128, which never happens for a = -1 and b, c up to u64
+//size
+pub fn quadratic_above_zero(a: i128, b: i128, c: i128) -> Result<Option<(i128, i128)>, String> {
+    assert!(a < 0, "a must be negative, got {a}");
+    //with t = 2|a|x - b the inequality is t^2 < b^2 - 4ac, so t runs from -s to s
+    //where s is the largest integer whose square is below that. the discriminant can
+    //outgrow i128 for u64 sized inputs, so it's built from magnitudes in u128
+    let overflow = || format!("solving {a}x^2 + {b}x + {c} overflowed");
+    let b_sq = b.unsigned_abs().checked_pow(2).ok_or_else(overflow)?;
+    let ac = a
+        .unsigned_abs()
+        .checked_mul(c.unsigned_abs())
+        .and_then(|ac| ac.checked_mul(4))
+        .ok_or_else(overflow)?;
+    let discriminant = if c < 0 {
+        match b_sq.checked_sub(ac) {
+            Some(d) => d,
+            None => return Ok(None),
+        }
+    } else {
+        b_sq.checked_add(ac).ok_or_else(overflow)?
+    };
+    let Some(below) = discriminant.checked_sub(1) else {
+        return Ok(None);
+    };
+    let step = a
+        .unsigned_abs()
+        .checked_mul(2)
+        .and_then(|step| i128::try_from(step).ok())
+        .ok_or_else(overflow)?;
+    //the root of a u128 is under 2^64, and |b| is too or b^2 would have overflowed,
+    //so nothing past here can
+    let s = isqrt(below) as i128;
+    let min = -(s - b).div_euclid(step);
+    let max = (b + s).div_euclid(step);
+    Ok((min <= max).then_some((min, max)))
+}
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use super::{rect::Rect, vec2::Vec2};
+
+type Point = Vec2<i64>;
+
+//a sparse grid: only the occupied positions are stored
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PointSet {
+    points: HashSet<Point>,
+}
+
+impl PointSet {
+    #[allow(dead_code)]
+    pub fn insert(&mut self, point: Point) -> bool {
+        self.points.insert(point)
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, point: &Point) -> bool {
+        self.points.contains(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Point> {
+        self.points.iter()
+    }
+
+    //the box around every point
+    pub fn bounds(&self) -> Option<Rect<i64>> {
+        Rect::bounding(self.points.iter().copied())
+    }
+
+    //sorted x values that have at least one point
+    pub fn columns(&self) -> Vec<i64> {
+        sorted_distinct(self.points.iter().map(|p| p.x))
+    }
+
+    //sorted y values that have at least one point
+    pub fn rows(&self) -> Vec<i64> {
+        sorted_distinct(self.points.iter().map(|p| p.y))
+    }
+
+    //x values inside the bounds with no points
+    pub fn empty_columns(&self) -> Vec<i64> {
+        self.bounds()
+            .map_or(Vec::new(), |r| gaps(&self.columns(), r.min.x, r.max.x))
+    }
+
+    //y values inside the bounds with no points
+    pub fn empty_rows(&self) -> Vec<i64> {
+        self.bounds()
+            .map_or(Vec::new(), |r| gaps(&self.rows(), r.min.y, r.max.y))
+    }
+
+    //renumbers the occupied columns and rows 0, 1, 2... so far apart points sit next to each other
+    #[allow(dead_code)]
+    pub fn compressed(&self) -> (PointSet, Compression) {
+        let compression = Compression {
+            columns: self.columns(),
+            rows: self.rows(),
+        };
+        let points = self
+            .points
+            .iter()
+            .map(|&p| {
+                compression
+                    .compress(p)
+                    .expect("every point is on its own lines")
+            })
+            .collect();
+        (PointSet { points }, compression)
+    }
+
+    pub fn stretcher(&self, origin: Point, factor: i64) -> Stretch {
+        Stretch {
+            origin,
+            lines: Compression {
+                columns: self.columns(),
+                rows: self.rows(),
+            },
+            factor,
+        }
+    }
+
+    //every empty column and row from origin onwards becomes factor of them, so origin
+    //should be above and left of every point
+    pub fn stretch(&self, origin: Point, factor: i64) -> PointSet {
+        let stretcher = self.stretcher(origin, factor);
+        self.points.iter().map(|&p| stretcher.apply(p)).collect()
+    }
+}
+
+impl FromIterator<Point> for PointSet {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        PointSet {
+            points: iter.into_iter().collect(),
+        }
+    }
+}
+
+//the occupied lines of a point set, in order, so positions can go back and forth
+#[derive(Debug, Clone)]
+pub struct Compression {
+    columns: Vec<i64>,
+    rows: Vec<i64>,
+}
+
+impl Compression {
+    //None if the point isn't on an occupied column and row
+    pub fn compress(&self, point: Point) -> Option<Point> {
+        Some(Vec2::new(
+            self.columns.binary_search(&point.x).ok()? as i64,
+            self.rows.binary_search(&point.y).ok()? as i64,
+        ))
+    }
+
+    #[allow(dead_code)]
+    pub fn decompress(&self, point: Point) -> Point {
+        Vec2::new(self.columns[point.x as usize], self.rows[point.y as usize])
+    }
+}
+
+//moves positions the way PointSet::stretch moves its points; origin stays put and empty
+//lines are counted from there
+#[derive(Debug, Clone)]
+pub struct Stretch {
+    origin: Point,
+    lines: Compression,
+    factor: i64,
+}
+
+impl Stretch {
+    pub fn apply(&self, point: Point) -> Point {
+        let occupied_before = Vec2::new(
+            self.lines.columns.partition_point(|&x| x < point.x) as i64,
+            self.lines.rows.partition_point(|&y| y < point.y) as i64,
+        );
+        let empty_before = point - self.origin - occupied_before;
+        point + empty_before * (self.factor - 1)
+    }
+}
+
+fn sorted_distinct(values: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut values: Vec<i64> = values.collect();
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+//values from min to max, both included, that aren't in the sorted list
+fn gaps(occupied: &[i64], min: i64, max: i64) -> Vec<i64> {
+    (min..=max)
+        .filter(|v| occupied.binary_search(v).is_err())
+        .collect()
+}
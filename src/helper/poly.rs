@@ -0,0 +1,83 @@
+//sequences read as a polynomial sampled at 0, 1, 2...
+
+use super::rational::Ratio;
+
+//the values, then their differences, then the differences of those, stopping at the
+//first row where every entry is the same, or when the rows run out. Err if a difference
+//doesn't fit in an i64
+pub fn difference_table(values: &[i64]) -> Result<Vec<Vec<i64>>, String> {
+    let mut table = vec![values.to_vec()];
+    loop {
+        let last = table.last().unwrap();
+        if last.len() < 2 || last.iter().all(|&v| v == last[0]) {
+            return Ok(table);
+        }
+        let next = last
+            .windows(2)
+            .map(|w| w[1].checked_sub(w[0]))
+            .collect::<Option<_>>()
+            .ok_or_else(|| format!("differences of {last:?} overflowed"))?;
+        table.push(next);
+    }
+}
+
+//the table, but only if it ends on a row that is constant with at least two entries to
+//show it. one value on its own could be anything, so two values are rejected too unless
+//they're equal: [1, 2] fits a line, but just as well a parabola or anything higher
+fn settled_table(values: &[i64]) -> Result<Vec<Vec<i64>>, String> {
+    let table = difference_table(values)?;
+    match table.last() {
+        Some(row) if row.len() >= 2 => Ok(table),
+        _ => Err(format!(
+            "differences never became constant in {} values",
+            values.len()
+        )),
+    }
+}
+
+//the lowest degree of polynomial that gives these values
+pub fn degree(values: &[i64]) -> Result<usize, String> {
+    Ok(settled_table(values)?.len() - 1)
+}
+
+//the value at any position, before, inside or after the sequence, using newton's forward
+//differences: f(x) = sum of the kth difference at 0 times x choose k
+pub fn extrapolate(values: &[i64], at: i64) -> Result<i64, String> {
+    let total = settled_table(values)?
+        .iter()
+        .enumerate()
+        .try_fold(0i128, |total, (k, row)| {
+            binomial(at, k)?
+                .checked_mul(row[0] as i128)?
+                .checked_add(total)
+        })
+        .ok_or_else(|| format!("value at {at} overflowed"))?;
+    i64::try_from(total).map_err(|_| format!("value at {at} is too large: {total}"))
+}
+
+//the same as extrapolate, through lagrange's formula instead: each sample's value times
+//the product of (at - j) / (i - j) over the other samples j. only the first degree + 1
+//samples are needed, and fractions keep every step exact
+pub fn lagrange(values: &[i64], at: i64) -> Result<i64, String> {
+    let count = degree(values)? + 1;
+    let overflow = || format!("value at {at} overflowed");
+    let mut total = Ratio::ZERO;
+    for (i, &value) in values[..count].iter().enumerate() {
+        let mut term = Ratio::from_integer(value as i128);
+        for j in (0..count).filter(|&j| j != i) {
+            let factor = Ratio::new(at as i128 - j as i128, i as i128 - j as i128);
+            term = term.checked_mul(factor).ok_or_else(overflow)?;
+        }
+        total = total.checked_add(term).ok_or_else(overflow)?;
+    }
+    let total = total.to_integer()?;
+    i64::try_from(total).map_err(|_| format!("value at {at} is too large: {total}"))
+}
+
+//x choose k for any integer x, so negative positions work too. every step divides exactly
+//because the running value is itself a binomial. None on overflow
+fn binomial(x: i64, k: usize) -> Option<i128> {
+    (0..k as i128).try_fold(1i128, |acc, i| {
+        Some(acc.checked_mul(x as i128 - i)? / (i + 1))
+    })
+}
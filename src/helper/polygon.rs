@@ -0,0 +1,26 @@
+use super::{num::gcd, vec2::Vec2};
+
+//the path is closed implicitly, from the last vertex back to the first. it can list every
+//lattice point along the way, or only the corners; straight runs add no area either way
+
+//twice the signed area, so it stays an integer. positive when the path turns clockwise
+//on screen, with y pointing down
+pub fn doubled_signed_area(path: &[Vec2<i64>]) -> i64 {
+    edges(path).map(|(a, b)| a.cross(*b)).sum()
+}
+
+//lattice points on the path itself, vertices included
+pub fn boundary_points(path: &[Vec2<i64>]) -> i64 {
+    edges(path)
+        .map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+        .sum()
+}
+
+//lattice points strictly inside, from pick's theorem: area = inside + boundary / 2 - 1
+pub fn interior_points(path: &[Vec2<i64>]) -> i64 {
+    (doubled_signed_area(path).abs() - boundary_points(path) + 2) / 2
+}
+
+fn edges(path: &[Vec2<i64>]) -> impl Iterator<Item = (&Vec2<i64>, &Vec2<i64>)> {
+    path.iter().zip(path.iter().cycle().skip(1))
+}
@@ -0,0 +1,229 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::num::gcd;
+
+//an exact fraction, always in lowest terms with a positive denominator, so equal values
+//compare equal field by field. the operators panic on overflow, the checked_ methods give
+//None instead. only i128 is implemented, which leaves room for products of i64 inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T,
+}
+
+impl Ratio<i128> {
+    pub const ZERO: Self = Ratio { numer: 0, denom: 1 };
+    #[allow(dead_code)]
+    pub const ONE: Self = Ratio { numer: 1, denom: 1 };
+
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "{numer}/0 has a zero denominator");
+        Self::reduced(numer, denom).expect("ratio overflowed")
+    }
+
+    //None for a zero denominator, or when the reduced value doesn't fit. the sizes are
+    //worked out in u128 so i128::MIN, which has no positive counterpart, still reduces
+    fn reduced(numer: i128, denom: i128) -> Option<Self> {
+        if denom == 0 {
+            return None;
+        }
+        let g = gcd(numer.unsigned_abs(), denom.unsigned_abs());
+        let (numer_size, denom_size) = (numer.unsigned_abs() / g, denom.unsigned_abs() / g);
+        let numer = if (numer < 0) != (denom < 0) {
+            0i128.checked_sub_unsigned(numer_size)?
+        } else {
+            i128::try_from(numer_size).ok()?
+        };
+        Some(Ratio {
+            numer,
+            denom: i128::try_from(denom_size).ok()?,
+        })
+    }
+
+    pub fn from_integer(n: i128) -> Self {
+        Ratio { numer: n, denom: 1 }
+    }
+
+    #[allow(dead_code)]
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    #[allow(dead_code)]
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    pub fn to_integer(self) -> Result<i128, String> {
+        if self.is_integer() {
+            Ok(self.numer)
+        } else {
+            Err(format!("{self} is not a whole number"))
+        }
+    }
+
+    //rounds towards negative infinity, like div_euclid
+    #[allow(dead_code)]
+    pub fn floor(self) -> i128 {
+        self.numer.div_euclid(self.denom)
+    }
+
+    //one past the floor for anything but a whole number, which never overflows: a value
+    //that isn't whole is below its ceiling, so the ceiling is at most i128::MAX
+    #[allow(dead_code)]
+    pub fn ceil(self) -> i128 {
+        self.floor() + (!self.is_integer()) as i128
+    }
+
+    //None for i128::MIN, which has no positive counterpart
+    #[allow(dead_code)]
+    pub fn checked_abs(self) -> Option<Self> {
+        Some(Ratio {
+            numer: self.numer.checked_abs()?,
+            denom: self.denom,
+        })
+    }
+
+    //None for zero
+    pub fn recip(self) -> Option<Self> {
+        Self::reduced(self.denom, self.numer)
+    }
+
+    //both sides are scaled by as little as possible before adding
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = checked_gcd(self.denom, rhs.denom)?;
+        let numer = self
+            .numer
+            .checked_mul(rhs.denom / g)?
+            .checked_add(rhs.numer.checked_mul(self.denom / g)?)?;
+        Self::reduced(numer, (self.denom / g).checked_mul(rhs.denom)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    //cancels across before multiplying, so only a result that can't fit overflows
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let g1 = checked_gcd(self.numer, rhs.denom)?;
+        let g2 = checked_gcd(rhs.numer, self.denom)?;
+        Self::reduced(
+            (self.numer / g1).checked_mul(rhs.numer / g2)?,
+            (self.denom / g2).checked_mul(rhs.denom / g1)?,
+        )
+    }
+
+    //None when dividing by zero, as well as on overflow
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Ratio {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom,
+        })
+    }
+}
+
+//gcd takes the magnitude of both sides, which panics on i128::MIN, so this goes through
+//u128. None only when the gcd is 2^127 itself
+fn checked_gcd(a: i128, b: i128) -> Option<i128> {
+    i128::try_from(gcd(a.unsigned_abs(), b.unsigned_abs())).ok()
+}
+
+impl From<i128> for Ratio<i128> {
+    fn from(n: i128) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl Add for Ratio<i128> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("ratio overflowed")
+    }
+}
+
+impl Sub for Ratio<i128> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("ratio overflowed")
+    }
+}
+
+impl Mul for Ratio<i128> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("ratio overflowed")
+    }
+}
+
+impl Div for Ratio<i128> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(rhs.numer != 0, "{self} divided by zero");
+        self.checked_div(rhs).expect("ratio overflowed")
+    }
+}
+
+impl Neg for Ratio<i128> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("ratio overflowed")
+    }
+}
+
+//compares whole parts, then the flipped remainders, like a continued fraction,
+//so nothing is multiplied and nothing can overflow
+impl Ord for Ratio<i128> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numer, self.denom);
+        let (mut c, mut d) = (other.numer, other.denom);
+        let mut flipped = false;
+        loop {
+            let order = a.div_euclid(b).cmp(&c.div_euclid(d));
+            let (ra, rc) = (a.rem_euclid(b), c.rem_euclid(d));
+            let order = match order {
+                Ordering::Equal if ra == 0 || rc == 0 => ra.cmp(&rc),
+                Ordering::Equal => {
+                    //a bigger remainder means a smaller flipped fraction
+                    (a, b, c, d) = (b, ra, d, rc);
+                    flipped = !flipped;
+                    continue;
+                }
+                order => order,
+            };
+            return if flipped { order.reverse() } else { order };
+        }
+    }
+}
+
+impl PartialOrd for Ratio<i128> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Ratio<i128> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
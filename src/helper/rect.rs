@@ -0,0 +1,101 @@
+use super::vec2::{Signed, Vec2};
+
+//an axis aligned rectangle, min and max both included. max below min on either axis
+//means there's nothing inside, which is what a zero sized grid gives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Vec2<T>,
+    pub max: Vec2<T>,
+}
+
+impl<T: Signed> Rect<T> {
+    //any two opposite corners, in any order
+    pub fn new(a: Vec2<T>, b: Vec2<T>) -> Self {
+        Rect {
+            min: Vec2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Vec2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    //0..size on both axes, like the positions of a grid
+    pub fn from_size(size: Vec2<T>) -> Self {
+        Rect {
+            min: Vec2::new(T::ZERO, T::ZERO),
+            max: size - Vec2::new(T::ONE, T::ONE),
+        }
+    }
+
+    //the smallest rectangle holding every point, or None without any
+    pub fn bounding(points: impl IntoIterator<Item = Vec2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rect::new(first, first), |r, p| r.union(&Rect::new(p, p))))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.max.x < self.min.x || self.max.y < self.min.y
+    }
+
+    pub fn contains(&self, point: Vec2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    #[allow(dead_code)]
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let out = Rect {
+            min: Vec2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Vec2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        };
+        (!out.is_empty()).then_some(out)
+    }
+
+    //the smallest rectangle covering both
+    pub fn union(&self, other: &Self) -> Self {
+        Rect {
+            min: Vec2::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Vec2::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    //positions along each axis; zero when empty
+    pub fn size(&self) -> Vec2<T> {
+        if self.is_empty() {
+            Vec2::new(T::ZERO, T::ZERO)
+        } else {
+            self.max - self.min + Vec2::new(T::ONE, T::ONE)
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn area(&self) -> T {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    //grows every side by amount, or shrinks them when it's negative
+    #[allow(dead_code)]
+    pub fn expand(&self, amount: T) -> Self {
+        let by = Vec2::new(amount, amount);
+        Rect {
+            min: self.min - by,
+            max: self.max + by,
+        }
+    }
+
+    //every position inside, in reading order
+    pub fn points(&self) -> impl Iterator<Item = Vec2<T>> {
+        let Rect { min, max } = *self;
+        let mut next = (!self.is_empty()).then_some(min);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = if current.x < max.x {
+                Some(Vec2::new(current.x + T::ONE, current.y))
+            } else if current.y < max.y {
+                Some(Vec2::new(min.x, current.y + T::ONE))
+            } else {
+                None
+            };
+            Some(current)
+        })
+    }
+}
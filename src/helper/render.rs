@@ -0,0 +1,110 @@
+use std::io::{stdout, IsTerminal};
+
+use super::{grid::Grid, rect::Rect, vec2::Vec2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    #[allow(dead_code)]
+    Cyan,
+}
+
+impl Color {
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+//marks some cells with a different character, a color, or both
+struct Overlay<'a> {
+    covers: Box<dyn Fn(Vec2<i32>) -> bool + 'a>,
+    glyph: Option<char>,
+    color: Option<Color>,
+}
+
+//draws a grid as text, one line per row. overlays are applied in the order they were
+//added, so later ones win where they overlap
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    cell: Box<dyn Fn(&T) -> char + 'a>,
+    overlays: Vec<Overlay<'a>>,
+    window: Option<Rect<i32>>,
+    colored: bool,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    //colors are on when stdout is a terminal, so redirecting to a file gives plain text
+    pub fn new(grid: &'a Grid<T>, cell: impl Fn(&T) -> char + 'a) -> Self {
+        Renderer {
+            grid,
+            cell: Box::new(cell),
+            overlays: Vec::new(),
+            window: None,
+            colored: stdout().is_terminal(),
+        }
+    }
+
+    //a color-only overlay vanishes without colors, so give it a glyph as well if that matters
+    pub fn overlay(
+        mut self,
+        covers: impl Fn(Vec2<i32>) -> bool + 'a,
+        glyph: Option<char>,
+        color: Option<Color>,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            covers: Box::new(covers),
+            glyph,
+            color,
+        });
+        self
+    }
+
+    //only draws from min to max, both included
+    pub fn window(mut self, min: Vec2<i32>, max: Vec2<i32>) -> Self {
+        self.window = Some(Rect::new(min, max));
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let Rect { min, max } = self.window.unwrap_or(self.grid.rect());
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let pos = Vec2::new(x, y);
+                let Some(value) = self.grid.get(pos) else {
+                    out.push(' ');
+                    continue;
+                };
+                let mut glyph = (self.cell)(value);
+                let mut color = None;
+                for overlay in self.overlays.iter().filter(|o| (o.covers)(pos)) {
+                    glyph = overlay.glyph.unwrap_or(glyph);
+                    color = overlay.color.or(color);
+                }
+                match color.filter(|_| self.colored) {
+                    Some(c) => out.push_str(&format!("\x1b[{}m{glyph}\x1b[0m", c.code())),
+                    None => out.push(glyph),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -0,0 +1,104 @@
+use std::ops::{Bound, RangeBounds};
+
+//SplitMix64; small, fast and the same sequence on every platform for a given seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    //uniform in 0..n, rejecting the biased tail so small ranges stay fair
+    fn below(&mut self, n: u128) -> u128 {
+        assert!(n > 0, "cannot sample from an empty range");
+        let n = u64::try_from(n).expect("range too large to sample");
+        let zone = u64::MAX - (u64::MAX % n);
+        loop {
+            let v = self.next_u64();
+            if v < zone {
+                return (v % n) as u128;
+            }
+        }
+    }
+
+    pub fn range<T, R>(&mut self, range: R) -> T
+    where
+        T: Uniform,
+        R: RangeBounds<T>,
+    {
+        let low = match range.start_bound() {
+            Bound::Included(&v) => v.to_i128(),
+            Bound::Excluded(&v) => v.to_i128() + 1,
+            Bound::Unbounded => T::MIN.to_i128(),
+        };
+        let high = match range.end_bound() {
+            Bound::Included(&v) => v.to_i128(),
+            Bound::Excluded(&v) => v.to_i128() - 1,
+            Bound::Unbounded => T::MAX.to_i128(),
+        };
+        assert!(low <= high, "cannot sample from an empty range");
+        let span = (high - low) as u128 + 1;
+        let offset = if span > u64::MAX as u128 {
+            //only reachable for the full range of a 64 bit type
+            self.next_u64() as u128
+        } else {
+            self.below(span)
+        };
+        T::from_i128(low + offset as i128)
+    }
+
+    //true with probability numerator / denominator
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(0..denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0..=i);
+            items.swap(i, j);
+        }
+    }
+}
+
+pub trait Uniform: Copy {
+    const MIN: Self;
+    const MAX: Self;
+    fn to_i128(self) -> i128;
+    fn from_i128(v: i128) -> Self;
+}
+
+macro_rules! impl_uniform {
+    ($($t:ty),*) => {
+        $(
+            impl Uniform for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(v: i128) -> Self {
+                    v as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_uniform!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
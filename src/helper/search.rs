@@ -0,0 +1,163 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+//anything that can be summed along a path and compared, with Default as zero
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T> Cost for T where T: Copy + Ord + Add<Output = T> + Default {}
+
+//what a search found: the cost to every node it reached and how it got there
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub distances: HashMap<N, C>,
+    pub previous: HashMap<N, N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+{
+    fn new(start: N) -> Self
+    where
+        C: Default,
+    {
+        Search {
+            distances: HashMap::from([(start, C::default())]),
+            previous: HashMap::new(),
+        }
+    }
+
+    //the nodes from the start to goal, both included, or None if goal was never reached
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(goal) {
+            return None;
+        }
+        let mut path = vec![goal.clone()];
+        while let Some(previous) = self.previous.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+//every edge costs one, so the distances are step counts
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        let depth = search.distances[&current];
+        for next in neighbors(&current) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), depth + 1);
+                search.previous.insert(next.clone(), current.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+//explores everything reachable; edge costs must not be negative
+#[allow(dead_code)]
+pub fn dijkstra<N, C, I>(start: N, neighbors: impl FnMut(&N) -> I) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, neighbors, |_| C::default(), |_| false).0
+}
+
+//stops at the first node where is_goal holds and returns it too. the heuristic must never
+//overestimate the remaining cost or the path found might not be the cheapest
+pub fn astar<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> (Search<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, neighbors, heuristic, is_goal)
+}
+
+fn best_first<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Search<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        //a cheaper way here was found after this entry was queued
+        if search.distances[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return (search, Some(node));
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if search.distances.get(&next).is_some_and(|&d| d <= next_cost) {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_cost);
+            search.previous.insert(next.clone(), node.clone());
+            queue.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+    (search, None)
+}
+
+//ordered by priority alone and reversed, so the max-heap pops the cheapest first
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
@@ -1,6 +1,8 @@
 use std::{
+    fmt::Debug,
     iter::Sum,
-    ops::{Add, AddAssign, Mul, Neg, Sub},
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -13,37 +15,261 @@ impl<T> Vec2<T> {
     pub fn new(x: T, y: T) -> Self {
         Vec2 { x, y }
     }
+
+    //like `as` on both components, but panics instead of wrapping when one doesn't fit.
+    //Vec2::<U>::try_from gives the error back instead
+    pub fn cast<U>(self) -> Vec2<U>
+    where
+        T: Debug + Copy,
+        Vec2<U>: TryFrom<Self>,
+    {
+        Vec2::try_from(self).unwrap_or_else(|_| panic!("{self:?} does not fit in the new type"))
+    }
+}
+
+//signed integers, so directions can point both ways
+pub trait Signed:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const NEG_ONE: Self;
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const NEG_ONE: Self = -1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+//y points down, like rows in the input
+impl<T: Signed> Vec2<T> {
+    pub const NORTH: Self = Vec2 {
+        x: T::ZERO,
+        y: T::NEG_ONE,
+    };
+    pub const SOUTH: Self = Vec2 {
+        x: T::ZERO,
+        y: T::ONE,
+    };
+    pub const EAST: Self = Vec2 {
+        x: T::ONE,
+        y: T::ZERO,
+    };
+    pub const WEST: Self = Vec2 {
+        x: T::NEG_ONE,
+        y: T::ZERO,
+    };
+    pub const NORTH_EAST: Self = Vec2 {
+        x: T::ONE,
+        y: T::NEG_ONE,
+    };
+    pub const SOUTH_EAST: Self = Vec2 {
+        x: T::ONE,
+        y: T::ONE,
+    };
+    pub const SOUTH_WEST: Self = Vec2 {
+        x: T::NEG_ONE,
+        y: T::ONE,
+    };
+    pub const NORTH_WEST: Self = Vec2 {
+        x: T::NEG_ONE,
+        y: T::NEG_ONE,
+    };
+    pub const ALL_CARDINAL: [Self; 4] = [Self::NORTH, Self::SOUTH, Self::EAST, Self::WEST];
+    #[allow(dead_code)]
+    pub const DIAGONALS: [Self; 4] = [
+        Self::NORTH_EAST,
+        Self::SOUTH_EAST,
+        Self::SOUTH_WEST,
+        Self::NORTH_WEST,
+    ];
+    pub const ALL_EIGHT: [Self; 8] = [
+        Self::NORTH,
+        Self::NORTH_EAST,
+        Self::EAST,
+        Self::SOUTH_EAST,
+        Self::SOUTH,
+        Self::SOUTH_WEST,
+        Self::WEST,
+        Self::NORTH_WEST,
+    ];
+}
+
+impl<T: Signed> Vec2<T> {
+    //steps between the two moving only along the axes
+    pub fn manhattan(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x + d.y
+    }
+
+    //steps between the two when diagonal moves are allowed too
+    #[allow(dead_code)]
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x.max(d.y)
+    }
+
+    //the squared straight line distance, which stays an integer
+    #[allow(dead_code)]
+    pub fn euclid_sq(self, other: Self) -> T {
+        let d = self - other;
+        d.dot(d)
+    }
+
+    pub fn abs(self) -> Self {
+        Vec2::new(self.x.abs(), self.y.abs())
+    }
+
+    //each component becomes -1, 0 or 1, turning an offset into a single step towards it
+    #[allow(dead_code)]
+    pub fn signum(self) -> Self {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+
+    //wraps each component into 0..size
+    pub fn rem_euclid(self, size: Self) -> Self {
+        Vec2::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
 }
 
 impl<T> Vec2<T>
 where
-    T: Neg<Output = T> + Copy,
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Copy,
 {
-    pub fn invert(&self) -> Self {
-        Vec2 {
-            x: -self.x,
-            y: -self.y,
-        }
+    #[allow(dead_code)]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    //the z component of the 3d cross product; positive when other is clockwise of self
+    //on screen, with y pointing down
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Mul<Output = T> + Div<Output = T>,
+{
+    #[allow(dead_code)]
+    pub fn component_mul(self, other: Self) -> Self {
+        Vec2::new(self.x * other.x, self.y * other.y)
+    }
+
+    #[allow(dead_code)]
+    pub fn component_div(self, other: Self) -> Self {
+        Vec2::new(self.x / other.x, self.y / other.y)
+    }
+}
+
+//unsigned positions can't step below zero, so moves come back as None instead of wrapping
+impl Vec2<usize> {
+    #[allow(dead_code)]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Vec2::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+        ))
+    }
+
+    #[allow(dead_code)]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Vec2::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    pub fn checked_add_signed(self, rhs: Vec2<isize>) -> Option<Self> {
+        Some(Vec2::new(
+            self.x.checked_add_signed(rhs.x)?,
+            self.y.checked_add_signed(rhs.y)?,
+        ))
+    }
+
+    //the move only counts if it also stays inside 0..size
+    #[allow(dead_code)]
+    pub fn checked_step(self, rhs: Vec2<isize>, size: Self) -> Option<Self> {
+        self.checked_add_signed(rhs)
+            .filter(|p| p.x < size.x && p.y < size.y)
     }
 }
 
-impl Vec2<i32> {
-    pub const NORTH: Self = Vec2 { x: 0, y: -1 };
-    pub const SOUTH: Self = Vec2 { x: 0, y: 1 };
-    pub const EAST: Self = Vec2 { x: 1, y: 0 };
-    pub const WEST: Self = Vec2 { x: -1, y: 0 };
-    pub const ALL_CARDINAL: &'static [Self] = &[Self::NORTH, Self::SOUTH, Self::EAST, Self::WEST];
+//between integer widths: From where every value fits, the same pairs std has From for,
+//and TryFrom for the rest, failing if either component is out of range
+macro_rules! impl_widen {
+    ($from:ty => $($to:ty),*) => {
+        $(
+            impl From<Vec2<$from>> for Vec2<$to> {
+                fn from(v: Vec2<$from>) -> Self {
+                    Vec2::new(v.x.into(), v.y.into())
+                }
+            }
+        )*
+    };
 }
 
-#[allow(dead_code)]
-impl Vec2<i64> {
-    pub const NORTH: Self = Vec2 { x: 0, y: -1 };
-    pub const SOUTH: Self = Vec2 { x: 0, y: 1 };
-    pub const EAST: Self = Vec2 { x: 1, y: 0 };
-    pub const WEST: Self = Vec2 { x: -1, y: 0 };
-    pub const ALL_CARDINAL: &'static [Self] = &[Self::NORTH, Self::SOUTH, Self::EAST, Self::WEST];
+macro_rules! impl_narrow {
+    ($from:ty => $($to:ty),*) => {
+        $(
+            impl TryFrom<Vec2<$from>> for Vec2<$to> {
+                type Error = TryFromIntError;
+
+                fn try_from(v: Vec2<$from>) -> Result<Self, Self::Error> {
+                    Ok(Vec2::new(v.x.try_into()?, v.y.try_into()?))
+                }
+            }
+        )*
+    };
 }
 
+impl_widen!(i8 => i16, i32, i64, i128, isize);
+impl_widen!(i16 => i32, i64, i128, isize);
+impl_widen!(i32 => i64, i128);
+impl_widen!(i64 => i128);
+impl_widen!(u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+impl_widen!(u16 => u32, u64, u128, usize, i32, i64, i128);
+impl_widen!(u32 => u64, u128, i64, i128);
+impl_widen!(u64 => u128, i128);
+
+impl_narrow!(i8 => u8, u16, u32, u64, u128, usize);
+impl_narrow!(i16 => i8, u8, u16, u32, u64, u128, usize);
+impl_narrow!(i32 => i8, i16, isize, u8, u16, u32, u64, u128, usize);
+impl_narrow!(i64 => i8, i16, i32, isize, u8, u16, u32, u64, u128, usize);
+impl_narrow!(i128 => i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+impl_narrow!(isize => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
+impl_narrow!(u8 => i8);
+impl_narrow!(u16 => i8, i16, isize, u8);
+impl_narrow!(u32 => i8, i16, i32, isize, u8, u16, usize);
+impl_narrow!(u64 => i8, i16, i32, i64, isize, u8, u16, u32, usize);
+impl_narrow!(u128 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+impl_narrow!(usize => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128);
+
 impl<T> Add for Vec2<T>
 where
     T: Add<Output = T>,
@@ -96,6 +322,69 @@ where
     }
 }
 
+impl<T> SubAssign for Vec2<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> MulAssign<T> for Vec2<T>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl<T> Div<T> for Vec2<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+//plain % on each component, so negative components stay negative; see rem_euclid
+impl<T> Rem<T> for Vec2<T>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x % rhs,
+            y: self.y % rhs,
+        }
+    }
+}
+
+impl<T> Neg for Vec2<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
 impl<T> Sum for Vec2<T>
 where
     T: Default + Add<Output = T>,
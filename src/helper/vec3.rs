@@ -0,0 +1,262 @@
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+use super::vec2::{Signed, Vec2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    #[allow(dead_code)]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    //drops z, looking down on the xy plane
+    #[allow(dead_code)]
+    pub fn xy(self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+
+    #[allow(dead_code)]
+    pub fn xz(self) -> Vec2<T> {
+        Vec2::new(self.x, self.z)
+    }
+
+    #[allow(dead_code)]
+    pub fn yz(self) -> Vec2<T> {
+        Vec2::new(self.y, self.z)
+    }
+}
+
+impl<T: Signed> Vec3<T> {
+    //the positions sharing a face with this one
+    #[allow(dead_code)]
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        [
+            Vec3::new(-one, zero, zero),
+            Vec3::new(one, zero, zero),
+            Vec3::new(zero, -one, zero),
+            Vec3::new(zero, one, zero),
+            Vec3::new(zero, zero, -one),
+            Vec3::new(zero, zero, one),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+
+    //the positions sharing a face, edge or corner with this one
+    #[allow(dead_code)]
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        let steps = [T::NEG_ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |z| {
+                steps
+                    .into_iter()
+                    .flat_map(move |y| steps.into_iter().map(move |x| Vec3::new(x, y, z)))
+            })
+            .filter(|&d| d != Vec3::new(T::ZERO, T::ZERO, T::ZERO))
+            .map(move |d| self + d)
+    }
+}
+
+//an axis aligned box, min and max both included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T: Signed> Cuboid<T> {
+    //any two opposite corners, in any order
+    #[allow(dead_code)]
+    pub fn new(a: Vec3<T>, b: Vec3<T>) -> Self {
+        Cuboid {
+            min: Vec3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Vec3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    //the smallest box holding every point, or None without any
+    #[allow(dead_code)]
+    pub fn bounding(points: impl IntoIterator<Item = Vec3<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Cuboid::new(first, first), |b, p| {
+            Cuboid::new(
+                Vec3::new(b.min.x.min(p.x), b.min.y.min(p.y), b.min.z.min(p.z)),
+                Vec3::new(b.max.x.max(p.x), b.max.y.max(p.y), b.max.z.max(p.z)),
+            )
+        }))
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, point: Vec3<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    #[allow(dead_code)]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Vec3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Vec3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Cuboid { min, max })
+    }
+}
+
+impl<T> Add for Vec3<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T> Sub for Vec3<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T> Mul<T> for Vec3<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Vec3<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
+
+//plain % on each component, so negative components stay negative
+impl<T> Rem<T> for Vec3<T>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x % rhs,
+            y: self.y % rhs,
+            z: self.z % rhs,
+        }
+    }
+}
+
+impl<T> Neg for Vec3<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T> AddAssign for Vec3<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T> SubAssign for Vec3<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> MulAssign<T> for Vec3<T>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl<T> Sum for Vec3<T>
+where
+    T: Default + Add<Output = T>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(
+            Vec3::new(T::default(), T::default(), T::default()),
+            |a, v| a + v,
+        )
+    }
+}
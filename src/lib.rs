@@ -20,7 +20,8 @@ pub fn include_all_day_files(_item: TokenStream) -> TokenStream {
         .map(|a| format!("{}, {}", a, a.strip_prefix("day").unwrap()))
         .collect::<Vec<String>>()
         .join(", ");
-    format!("main_day!(run_day, get_default_day, {});", arguments)
+    let functions = "run_day, get_default_day, generate_day, diff_day, bench_day, repl_day";
+    format!("main_day!({functions}, {arguments});")
         .parse()
         .unwrap()
 }
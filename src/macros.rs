@@ -9,19 +9,20 @@ macro_rules! _count_tts {
 }
 
 macro_rules! main_day {
-    ( $run_day:ident, $get_default_day:ident, $($day:ident, $day_value:expr),*$(,)* ) => {
+    ( $run_day:ident, $get_default_day:ident, $generate_day:ident, $diff_day:ident, $bench_day:ident, $repl_day:ident, $($day:ident, $day_value:expr),*$(,)* ) => {
         $(
             mod $day;
         )*
 
-        pub fn $run_day(day: usize) -> Option<()>{
+        pub fn $run_day(day: usize, variant: Option<&str>, input: Option<&str>, report: bool) -> Option<()>{
             $(
                 if day == $day_value {
                     use $day::*;
+                    let input = load(input.unwrap_or(INPUT), report);
                     println!();
-                    part1.time(1);
+                    run_part(1, &input, PART1, variant);
                     println!();
-                    part2.time(2);
+                    run_part(2, &input, PART2, variant);
                     println!();
                     return Some(())
                 }
@@ -30,6 +31,71 @@ macro_rules! main_day {
             return None
         }
 
+        pub fn $generate_day(day: usize, seed: u64, scale: usize) -> Option<String> {
+            $(
+                if day == $day_value {
+                    let mut rng = crate::helper::rng::Rng::new(seed);
+                    return Some($day::generate(&mut rng, scale))
+                }
+            )*
+
+            return None
+        }
+
+        pub fn $diff_day(day: usize, seed: u64, count: u64, scale: usize) -> Option<bool> {
+            $(
+                if day == $day_value {
+                    use $day::*;
+                    let examples = EXAMPLES
+                        .iter()
+                        .enumerate()
+                        .map(|(i, e)| (format!("example {}", i + 1), load(e, false)));
+                    let generated = (seed..seed + count).map(|s| {
+                        let mut rng = crate::helper::rng::Rng::new(s);
+                        (format!("seed {s}"), load(&generate(&mut rng, scale), false))
+                    });
+                    for (name, input) in examples.chain(generated) {
+                        if !diff_part(1, &name, &input, part1, reference1)
+                            || !diff_part(2, &name, &input, part2, reference2)
+                        {
+                            return Some(false)
+                        }
+                    }
+                    return Some(true)
+                }
+            )*
+
+            return None
+        }
+
+        pub fn $bench_day(day: usize, runs: u32, input: Option<&str>, report: bool) -> Option<bool> {
+            $(
+                if day == $day_value {
+                    use $day::*;
+                    let input = load(input.unwrap_or(INPUT), report);
+                    println!();
+                    let part1_agrees = bench_part(1, &input, PART1, runs);
+                    println!();
+                    let part2_agrees = bench_part(2, &input, PART2, runs);
+                    println!();
+                    return Some(part1_agrees && part2_agrees)
+                }
+            )*
+
+            return None
+        }
+
+        pub fn $repl_day(day: usize, report: bool) -> Option<()> {
+            $(
+                if day == $day_value {
+                    $day::repl(&load($day::INPUT, report));
+                    return Some(())
+                }
+            )*
+
+            return None
+        }
+
         pub fn $get_default_day() -> String {
             let days = [
                 $($day_value, )*
@@ -1,4 +1,9 @@
-use std::env::args;
+use std::{
+    env::args,
+    fs::read_to_string,
+    io::{stdin, Read},
+    str::FromStr,
+};
 
 #[macro_use]
 mod macros;
@@ -6,8 +11,124 @@ mod days;
 mod helper;
 
 fn main() {
-    let s = args().nth(1).unwrap_or_else(|| days::get_default_day());
+    let args: Vec<String> = args().skip(1).collect();
+    match args.first().map(|s| s.as_str()) {
+        Some("generate") => generate(&args[1..]),
+        Some("diff") => diff(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("repl") => repl(&args[1..]),
+        _ => run(&args),
+    }
+}
+
+//[day] [--variant NAME] [--input FILE] [--report-input]
+fn run(args: &[String]) {
+    let s = args
+        .first()
+        .filter(|a| !a.starts_with("--"))
+        .cloned()
+        .unwrap_or_else(|| days::get_default_day());
+    let variant: Option<String> = get_flag(args, "--variant");
+    let input = get_input(args);
     println!("== Day {s} ==");
-    days::run_day(s.parse().expect("day is not a number"))
-        .expect("could not find function for day");
+    let report = args.iter().any(|a| a == "--report-input");
+    days::run_day(
+        s.parse().expect("day is not a number"),
+        variant.as_deref(),
+        input.as_deref(),
+        report,
+    )
+    .expect("could not find function for day");
+}
+
+//bench <day> [--runs N] [--input FILE] [--report-input]
+fn bench(args: &[String]) {
+    let day = args
+        .first()
+        .expect("bench needs a day")
+        .parse()
+        .expect("day is not a number");
+    let runs = get_flag(args, "--runs").unwrap_or(1);
+    assert!(runs > 0, "runs must be at least 1");
+    let input = get_input(args);
+    println!("== Day {day} ==");
+    let report = args.iter().any(|a| a == "--report-input");
+    if !days::bench_day(day, runs, input.as_deref(), report)
+        .expect("could not find function for day")
+    {
+        println!("variants disagree");
+    }
+}
+
+//generate <day> [--seed S] [--scale K]
+fn generate(args: &[String]) {
+    let day = args
+        .first()
+        .expect("generate needs a day")
+        .parse()
+        .expect("day is not a number");
+    let seed = get_flag(args, "--seed").unwrap_or(0);
+    let scale = get_flag(args, "--scale").unwrap_or(1);
+    assert!(scale > 0, "scale must be at least 1");
+    let input = days::generate_day(day, seed, scale).expect("could not find generator for day");
+    print!("{input}");
+}
+
+//diff <day> [--seed S] [--count N] [--scale K]
+fn diff(args: &[String]) {
+    let day = args
+        .first()
+        .expect("diff needs a day")
+        .parse()
+        .expect("day is not a number");
+    let seed = get_flag(args, "--seed").unwrap_or(0);
+    let count = get_flag(args, "--count").unwrap_or(100);
+    let scale = get_flag(args, "--scale").unwrap_or(1);
+    assert!(scale > 0, "scale must be at least 1");
+    println!("== Day {day} ==");
+    if days::diff_day(day, seed, count, scale).expect("could not find function for day") {
+        println!(
+            "no differences on the examples or seeds {seed}..{}",
+            seed + count
+        );
+    }
+}
+
+//repl <day> [--report-input]
+fn repl(args: &[String]) {
+    let day = args
+        .first()
+        .expect("repl needs a day")
+        .parse()
+        .expect("day is not a number");
+    println!("== Day {day} ==");
+    let report = args.iter().any(|a| a == "--report-input");
+    days::repl_day(day, report).expect("could not find function for day");
+}
+
+//what --input names instead of the day's own input: a file, or - for stdin, so
+//generated inputs can be piped straight in
+fn get_input(args: &[String]) -> Option<String> {
+    let path: String = get_flag(args, "--input")?;
+    if path == "-" {
+        let mut text = String::new();
+        stdin()
+            .read_to_string(&mut text)
+            .expect("could not read stdin");
+        Some(text)
+    } else {
+        Some(read_to_string(&path).unwrap_or_else(|e| panic!("could not read {path}: {e}")))
+    }
+}
+
+fn get_flag<T: FromStr>(args: &[String], flag: &str) -> Option<T> {
+    let i = args.iter().position(|a| a == flag)?;
+    let value = args
+        .get(i + 1)
+        .unwrap_or_else(|| panic!("missing value for {flag}"));
+    Some(
+        value
+            .parse()
+            .unwrap_or_else(|_| panic!("could not parse value for {flag}: {value}")),
+    )
 }